@@ -1,7 +1,6 @@
 use clemen::layouts::{
     LayoutType,
     element::{Element, PositionStyle},
-    flexible::Direction,
 };
 use std::time::SystemTime;
 
@@ -40,7 +39,7 @@ fn main() {
     ));
 
     // test resizing
-    root.sublayout.resize_flexible(Direction::X);
+    root.sublayout.resize_flexible();
 
     // done
     println!(
@@ -1,6 +1,4 @@
-use clemen::layouts::{
-    AlignmentX, LayoutProperties, LayoutType, element::Element, flexible::Direction,
-};
+use clemen::layouts::{AlignmentX, LayoutProperties, LayoutType, element::Element};
 use std::time::SystemTime;
 
 fn main() {
@@ -10,7 +8,7 @@ fn main() {
         LayoutType::Flexible,
     );
     root.sublayout.properties = LayoutProperties {
-        offset: 0.0,
+        offset: 0.0.into(),
         align_x: AlignmentX::Center,
         flex_grow: false,
         ..Default::default()
@@ -25,7 +23,7 @@ fn main() {
         ));
     }
 
-    root.sublayout.resize_flexible(Direction::X);
+    root.sublayout.resize_flexible();
     // root.sublayout.revert_flexible();
 
     println!(
@@ -1,5 +1,5 @@
 use clemen::layouts::{
-    AlignmentX, LayoutProperties, LayoutType, element::Element, flexible::Direction,
+    AlignmentX, Direction, LayoutProperties, LayoutType, Overflow, element::Element,
 };
 use std::time::SystemTime;
 
@@ -10,12 +10,13 @@ fn main() {
         LayoutType::Flexible,
     );
     root.sublayout.properties = LayoutProperties {
-        offset: 0.0,
+        offset: 0.0.into(),
         align_x: AlignmentX::Center,
         flex_grow: false,
         ..Default::default()
     };
-    root.sublayout.col = true;
+    root.sublayout.overflow = Overflow::Wrap;
+    root.sublayout.direction = Direction::Y; // centered along the main axis
 
     let start = SystemTime::now();
     for _ in 0..2 {
@@ -26,7 +27,7 @@ fn main() {
         ));
     }
 
-    root.sublayout.resize_flexible(Direction::Y);
+    root.sublayout.resize_flexible();
     // root.sublayout.revert_flexible();
 
     println!(
@@ -0,0 +1,32 @@
+use clemen::layouts::{AlignContent, LayoutProperties, LayoutType, element::Element};
+use std::time::SystemTime;
+
+fn main() {
+    // a tag cloud, which sits in the middle of its panel
+    let mut root = Element::new(
+        (300.0.into(), 300.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+    root.sublayout.properties = LayoutProperties {
+        offset: 8.0.into(),
+        align_content: AlignContent::Center,
+        ..Default::default()
+    };
+
+    let start = SystemTime::now();
+    for width in [60.0, 90.0, 40.0, 120.0, 70.0, 50.0, 80.0] {
+        root.sublayout.add(Element::new(
+            (width.into(), 24.0.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
@@ -0,0 +1,59 @@
+use clemen::layouts::{
+    AlignmentY, LayoutProperties, LayoutType,
+    element::{Element, ElementAttributes},
+};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (400.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Flexible,
+    );
+    root.sublayout.properties = LayoutProperties {
+        offset: 0.0.into(),
+        align_y: AlignmentY::Center,
+        ..Default::default()
+    };
+
+    let start = SystemTime::now();
+    for height in [20.0, 40.0, 60.0] {
+        root.sublayout.add(Element::new(
+            (80.0.into(), height.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    // one element sits at the bottom of the row instead...
+    let mut bottom = Element::new(
+        (80.0.into(), 20.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+    bottom.attrs = ElementAttributes {
+        align_self: Some(AlignmentY::Bottom),
+        ..Default::default()
+    };
+    root.sublayout.add(bottom);
+
+    // ...and another fills as much of it as it can
+    let mut stretched = Element::new(
+        (80.0.into(), 20.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+    stretched.attrs = ElementAttributes {
+        max_size: Some((80.0.into(), 90.0.into())),
+        align_self: Some(AlignmentY::Stretch),
+        ..Default::default()
+    };
+    root.sublayout.add(stretched);
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
@@ -0,0 +1,43 @@
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (500.0.into(), 300.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+
+    let start = SystemTime::now();
+
+    // a toolbar which is only as big as the buttons inside of it
+    let mut toolbar = Element::new(
+        (SizeUnit::Auto, SizeUnit::Auto),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+
+    for _ in 0..3 {
+        toolbar.sublayout.add(Element::new(
+            (50.0.into(), 30.0.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    root.sublayout.add(toolbar);
+
+    // this should sit right next to the toolbar
+    root.sublayout.add(Element::new(
+        (100.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    ));
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
@@ -8,7 +8,7 @@ fn main() {
         LayoutType::Block,
     );
     root.sublayout.properties = LayoutProperties {
-        offset: 0.0,
+        offset: 0.0.into(),
         ..Default::default()
     };
 
@@ -0,0 +1,42 @@
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (1000.0.into(), 400.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+
+    let start = SystemTime::now();
+
+    // clamp(200px, 30%, 400px)
+    root.sublayout.add(Element::new(
+        (
+            SizeUnit::Percent(30.0).clamp(200.0.into(), 400.0.into()),
+            SizeUnit::Percent(100.0),
+        ),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    ));
+
+    // the rest of the row, minus a gutter: calc(60% - 48px)
+    root.sublayout.add(Element::new(
+        (
+            SizeUnit::Percent(60.0) - SizeUnit::Pixel(48.0),
+            SizeUnit::Percent(100.0),
+        ),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    ));
+
+    // shrinking the root re-evaluates both expressions
+    root.resize((500.0.into(), 400.0.into()));
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
@@ -0,0 +1,41 @@
+use clemen::layouts::{LayoutProperties, LayoutType, element::Element, unit::SizeUnit};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (400.0.into(), 200.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+    root.sublayout.properties = LayoutProperties {
+        offset: SizeUnit::Em(1.0),
+        font_size: Some(10.0.into()),
+        ..Default::default()
+    };
+
+    let start = SystemTime::now();
+    for _ in 0..4 {
+        let mut element = Element::new(
+            (SizeUnit::Em(8.0), SizeUnit::Em(4.0)),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        );
+
+        // children of this element use a font twice the size of the root's
+        element.sublayout.properties.font_size = Some(SizeUnit::Em(2.0));
+        element.sublayout.add(Element::new(
+            (SizeUnit::Em(2.0), SizeUnit::Rem(2.0)),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+
+        root.sublayout.add(element);
+    }
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
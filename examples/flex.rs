@@ -1,4 +1,4 @@
-use clemen::layouts::{LayoutType, element::Element, flexible::Direction};
+use clemen::layouts::{LayoutType, element::Element};
 use std::time::SystemTime;
 
 fn main() {
@@ -17,7 +17,7 @@ fn main() {
         ));
     }
 
-    root.sublayout.resize_flexible(Direction::X);
+    root.sublayout.resize_flexible();
     // root.sublayout.revert_flexible();
 
     println!(
@@ -1,4 +1,4 @@
-use clemen::layouts::{LayoutType, element::Element, flexible::Direction};
+use clemen::layouts::{LayoutType, Overflow, element::Element};
 use std::time::SystemTime;
 
 fn main() {
@@ -7,7 +7,7 @@ fn main() {
         (0.0.into(), 0.0.into()),
         LayoutType::Flexible,
     );
-    root.sublayout.col = true; // this creates a column layout
+    root.sublayout.overflow = Overflow::Wrap; // this creates a column layout
 
     let start = SystemTime::now();
     for _ in 0..100 {
@@ -18,8 +18,7 @@ fn main() {
         ));
     }
 
-    // root.sublayout.resize_flexible(Direction::X);
-    root.sublayout.resize_flexible(Direction::Y);
+    root.sublayout.resize_flexible();
     // root.sublayout.revert_flexible();
 
     println!(
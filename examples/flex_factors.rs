@@ -0,0 +1,62 @@
+use clemen::layouts::{
+    LayoutProperties, LayoutType,
+    element::{Element, ElementAttributes},
+    unit::SizeUnit,
+};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (600.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Flexible,
+    );
+    root.sublayout.properties = LayoutProperties {
+        offset: 0.0.into(),
+        ..Default::default()
+    };
+
+    let start = SystemTime::now();
+
+    // a toolbar which never changes size
+    let mut toolbar = Element::new(
+        (48.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+    toolbar.attrs = ElementAttributes {
+        flex_grow: 0.0,
+        flex_shrink: 0.0,
+        ..Default::default()
+    };
+    root.sublayout.add(toolbar);
+
+    // a content pane which takes all of the free space
+    root.sublayout.add(Element::new(
+        (200.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    ));
+
+    // a sidebar which starts at a quarter of the row, and only ever shrinks
+    let mut sidebar = Element::new(
+        (100.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+    sidebar.attrs = ElementAttributes {
+        flex_grow: 0.0,
+        flex_basis: SizeUnit::Percent(25.0),
+        ..Default::default()
+    };
+    root.sublayout.add(sidebar);
+
+    root.sublayout.resize_flexible();
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
@@ -0,0 +1,46 @@
+use clemen::layouts::{
+    LayoutProperties, LayoutType,
+    element::{Element, ElementAttributes},
+};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (300.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Flexible,
+    );
+    root.sublayout.properties = LayoutProperties {
+        offset: 0.0.into(),
+        ..Default::default()
+    };
+
+    let start = SystemTime::now();
+    for i in 0..3 {
+        let mut element = Element::new(
+            (200.0.into(), 100.0.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        );
+
+        if i == 0 {
+            // the first element can't shrink as far as the others, so they have
+            // to shrink further to make up for it
+            element.attrs = ElementAttributes {
+                min_size: Some((150.0.into(), 0.5.into())),
+                ..Default::default()
+            };
+        }
+
+        root.sublayout.add(element);
+    }
+
+    root.sublayout.resize_flexible();
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
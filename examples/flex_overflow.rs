@@ -1,4 +1,4 @@
-use clemen::layouts::{LayoutProperties, LayoutType, element::Element, flexible::Direction};
+use clemen::layouts::{LayoutProperties, LayoutType, element::Element};
 use std::time::SystemTime;
 
 fn main() {
@@ -8,7 +8,7 @@ fn main() {
         LayoutType::Flexible,
     );
     root.sublayout.properties = LayoutProperties {
-        offset: 0.0,
+        offset: 0.0.into(),
         ..Default::default()
     };
 
@@ -21,7 +21,7 @@ fn main() {
         ));
     }
 
-    root.sublayout.resize_flexible(Direction::X);
+    root.sublayout.resize_flexible();
     // root.sublayout.revert_flexible();
 
     println!(
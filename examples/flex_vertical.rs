@@ -0,0 +1,30 @@
+use clemen::layouts::{Direction, LayoutType, Overflow, element::Element};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (100.0.into(), 200.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Flexible,
+    );
+    root.sublayout.direction = Direction::Y; // elements are placed top-to-bottom
+    root.sublayout.overflow = Overflow::Wrap; // and wrap onto new columns
+
+    let start = SystemTime::now();
+    for _ in 0..100 {
+        root.sublayout.add(Element::new(
+            (50.0.into(), 200.0.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    root.sublayout.resize_flexible();
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
@@ -0,0 +1,42 @@
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (600.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Flexible,
+    );
+
+    let start = SystemTime::now();
+
+    // sidebar
+    root.sublayout.add(Element::new(
+        (SizeUnit::Fraction(1.0), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    ));
+
+    // fixed divider, this takes its space before the fractions do
+    root.sublayout.add(Element::new(
+        (200.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    ));
+
+    // content
+    root.sublayout.add(Element::new(
+        (SizeUnit::Fraction(3.0), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    ));
+
+    root.sublayout.resize_flexible();
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
@@ -0,0 +1,32 @@
+use clemen::layouts::{LayoutProperties, LayoutType, element::Element, unit::SizeUnit};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (330.0.into(), 400.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+    // a grid of cards, with wider gutters between rows than between columns
+    root.sublayout.properties = LayoutProperties {
+        row_gap: Some(SizeUnit::Rem(2.0)),
+        column_gap: Some(15.0.into()),
+        ..Default::default()
+    };
+
+    let start = SystemTime::now();
+    for _ in 0..7 {
+        root.sublayout.add(Element::new(
+            (100.0.into(), 60.0.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
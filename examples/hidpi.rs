@@ -0,0 +1,31 @@
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (100.0.into(), 50.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Flexible,
+    );
+    root.sublayout.scale_factor = 1.25;
+    root.sublayout.pixel_snap = true;
+
+    let start = SystemTime::now();
+    for _ in 0..3 {
+        root.sublayout.add(Element::new(
+            (SizeUnit::Fraction(1.0), 50.0.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    // every edge should land on a whole device pixel, without leaving seams
+    root.sublayout.resize_flexible();
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
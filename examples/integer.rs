@@ -0,0 +1,29 @@
+use clemen::layouts::{LayoutType, element::Element};
+use std::time::SystemTime;
+
+fn main() {
+    // layouts can be calculated in any scalar type, not just `f64`
+    let mut root: Element<i32> = Element::new(
+        (300.into(), 100.into()),
+        (0.into(), 0.into()),
+        LayoutType::Flexible,
+    );
+
+    let start = SystemTime::now();
+    for _ in 0..4 {
+        root.sublayout.add(Element::new(
+            (100.into(), 100.into()),
+            (0.into(), 0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    root.sublayout.resize_flexible();
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
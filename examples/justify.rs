@@ -0,0 +1,36 @@
+use clemen::layouts::{Justify, LayoutProperties, LayoutType, element::Element};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (400.0.into(), 40.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Flexible,
+    );
+    root.sublayout.properties = LayoutProperties {
+        offset: 4.0.into(),
+        flex_grow: false,
+        justify: Justify::SpaceEvenly,
+        ..Default::default()
+    };
+
+    let start = SystemTime::now();
+    for _ in 0..3 {
+        root.sublayout.add(Element::new(
+            (60.0.into(), 40.0.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    // the free space is shared evenly between the gaps (and both ends), on top
+    // of the offset between each element
+    root.sublayout.resize_flexible();
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
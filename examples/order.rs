@@ -0,0 +1,41 @@
+use clemen::layouts::{
+    LayoutType,
+    element::{Element, ElementAttributes},
+};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (400.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+
+    let start = SystemTime::now();
+    for width in [50.0, 100.0, 150.0] {
+        root.sublayout.add(Element::new(
+            (width.into(), 100.0.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    // the last element is placed first, but keeps its index (and id)
+    let mut first = Element::new(
+        (75.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+    first.attrs = ElementAttributes {
+        order: -1,
+        ..Default::default()
+    };
+    root.sublayout.add(first);
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
@@ -0,0 +1,35 @@
+use clemen::layouts::{LayoutProperties, LayoutType, Overflow, element::Element};
+use std::time::SystemTime;
+
+fn main() {
+    // a toolbar which drops the buttons that don't fit, instead of squashing them
+    let mut root = Element::new(
+        (250.0.into(), 40.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Flexible,
+    );
+    root.sublayout.properties = LayoutProperties {
+        flex_grow: false,
+        ..Default::default()
+    };
+    root.sublayout.overflow = Overflow::Hide;
+
+    let start = SystemTime::now();
+    for _ in 0..5 {
+        root.sublayout.add(Element::new(
+            (60.0.into(), 40.0.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    root.sublayout.resize_flexible();
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+    println!("hidden: {:?}", root.sublayout.overflowed());
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
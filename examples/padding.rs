@@ -0,0 +1,39 @@
+use clemen::layouts::{AlignmentY, LayoutProperties, LayoutType, Padding, element::Element};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (300.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Flexible,
+    );
+    root.sublayout.properties = LayoutProperties {
+        padding: Padding {
+            top: 10.0.into(),
+            right: 20.0.into(),
+            bottom: 10.0.into(),
+            left: 20.0.into(),
+        },
+        align_y: AlignmentY::Stretch,
+        ..Default::default()
+    };
+
+    let start = SystemTime::now();
+    for _ in 0..3 {
+        root.sublayout.add(Element::new(
+            (50.0.into(), 50.0.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    // elements grow (and stretch) to fill the space inside of the padding only
+    root.sublayout.resize_flexible();
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
@@ -0,0 +1,29 @@
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (200.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+
+    let start = SystemTime::now();
+    for _ in 0..4 {
+        root.sublayout.add(Element::new(
+            (SizeUnit::Percent(50.0), SizeUnit::Percent(50.0)),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    // every element should still fill a quarter of the root after it grows
+    root.resize((400.0.into(), 200.0.into()));
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
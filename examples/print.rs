@@ -0,0 +1,36 @@
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+use std::time::SystemTime;
+
+fn main() {
+    // a 4in x 2in label, printed at 300 dpi
+    let mut root = Element::new(
+        (1200.0.into(), 600.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+    root.sublayout.dpi = 300.0;
+
+    let start = SystemTime::now();
+
+    // barcode
+    root.sublayout.add(Element::new(
+        (SizeUnit::Millimetre(50.0), SizeUnit::Millimetre(25.4)),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    ));
+
+    // a line of 12pt text
+    root.sublayout.add(Element::new(
+        (SizeUnit::Inch(1.5), SizeUnit::Point(12.0)),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    ));
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    // sizes are written back out in the units they were given in
+    std::fs::write("out.html", root.html()).unwrap();
+}
@@ -0,0 +1,36 @@
+use clemen::layouts::{Direction, LayoutProperties, LayoutType, element::Element};
+use std::time::SystemTime;
+
+fn main() {
+    // a chat window, where the newest message (the first element) sits at the bottom
+    let mut root = Element::new(
+        (200.0.into(), 300.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Flexible,
+    );
+    root.sublayout.properties = LayoutProperties {
+        offset: 4.0.into(),
+        flex_grow: false,
+        ..Default::default()
+    };
+    root.sublayout.direction = Direction::Y;
+    root.sublayout.reverse = true;
+
+    let start = SystemTime::now();
+    for height in [30.0, 60.0, 30.0, 45.0] {
+        root.sublayout.add(Element::new(
+            (200.0.into(), height.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    root.sublayout.resize_flexible();
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
@@ -0,0 +1,33 @@
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+use std::time::SystemTime;
+
+fn main() {
+    // an 80x24 terminal, everything is measured in cells
+    let mut root = Element::new(
+        (80.0.into(), 24.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Flexible,
+    );
+    root.sublayout.cell_grid = true;
+
+    let start = SystemTime::now();
+
+    // three panes which can't split 80 cells evenly, they should still tile the
+    // whole terminal without any gaps
+    for _ in 0..3 {
+        root.sublayout.add(Element::new(
+            (SizeUnit::Fraction(1.0), SizeUnit::Cell(24.0)),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    root.sublayout.resize_flexible();
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
@@ -0,0 +1,38 @@
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+use std::time::SystemTime;
+
+fn main() {
+    let mut root = Element::new(
+        (800.0.into(), 600.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+
+    let start = SystemTime::now();
+
+    // a panel which only takes up part of the root
+    let mut panel = Element::new(
+        (SizeUnit::Percent(50.0), SizeUnit::Percent(50.0)),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+
+    // the modal is still sized relative to the root, not the panel
+    panel.sublayout.add(Element::new(
+        (SizeUnit::Vw(80.0), SizeUnit::Vh(60.0)),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    ));
+
+    root.sublayout.add(panel);
+
+    // resizing the root should resize the modal too
+    root.resize((1000.0.into(), 800.0.into()));
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
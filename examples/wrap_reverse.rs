@@ -0,0 +1,33 @@
+use clemen::layouts::{LayoutProperties, LayoutType, Padding, element::Element};
+use std::time::SystemTime;
+
+fn main() {
+    // a bottom-anchored panel, where each new row sits above the last
+    let mut root = Element::new(
+        (300.0.into(), 200.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    );
+    root.sublayout.properties = LayoutProperties {
+        offset: 10.0.into(),
+        padding: Padding::all(10.0.into()),
+        ..Default::default()
+    };
+    root.sublayout.wrap_reverse = true;
+
+    let start = SystemTime::now();
+    for _ in 0..5 {
+        root.sublayout.add(Element::new(
+            (120.0.into(), 40.0.into()),
+            (0.0.into(), 0.0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    println!(
+        "finished calculating, took: {}μs",
+        start.elapsed().unwrap().as_micros()
+    );
+
+    std::fs::write("out.html", root.html()).unwrap();
+}
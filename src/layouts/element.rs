@@ -1,94 +1,173 @@
+use super::Direction;
+use super::scalar::Scalar;
 use super::unit::SizeUnit;
-use super::{Layout, LayoutType};
+use super::{AlignmentY, Layout, LayoutType};
 
 /// A 2D vector.
-pub type Vector2 = (SizeUnit, SizeUnit);
+pub type Vector2<S = f64> = (SizeUnit<S>, SizeUnit<S>);
 
 /// The way and element is positioned.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub enum PositionStyle {
     /// Relative to the other elements within its container.
+    #[default]
     Relative,
     /// Not counted as a child of the element; position and size values are absolute.
     Absolute,
 }
 
-impl Default for PositionStyle {
-    fn default() -> Self {
-        Self::Relative
-    }
-}
-
 /// Specific elements which change the behavior of an [`Element`] in layouts.
 #[derive(Clone, Debug)]
-pub struct ElementAttributes {
+pub struct ElementAttributes<S = f64> {
     /// The minimum sizes of the element. The element's width or height cannot
     /// fall below these values
-    pub min_size: Option<Vector2>,
+    pub min_size: Option<Vector2<S>>,
     /// The maximum sizes of the element. The element's width or height cannot
     /// fall below these values
-    pub max_size: Option<Vector2>,
+    pub max_size: Option<Vector2<S>>,
     /// The type of positioning the element follows.
     pub style: PositionStyle,
+    /// How much of the free space in a flexible layout the element takes, relative
+    /// to its siblings.
+    pub flex_grow: S,
+    /// How much the element shrinks when a flexible layout overflows, relative to
+    /// its siblings. Multiplied by the element's basis, so larger elements give up more.
+    pub flex_shrink: S,
+    /// The size of the element along the direction a flexible layout is resized in,
+    /// before it grows or shrinks. [`SizeUnit::Auto`] uses the element's own size.
+    pub flex_basis: SizeUnit<S>,
+    /// Overrides the layout's [`AlignmentY`] for this element.
+    pub align_self: Option<AlignmentY>,
+    /// Where the element is placed relative to its siblings. Elements are placed from
+    /// the lowest order to the highest, and in the order they were added when the
+    /// orders are the same. The element's index in the layout doesn't change.
+    pub order: i32,
 }
 
-impl Default for ElementAttributes {
+impl<S: Scalar> Default for ElementAttributes<S> {
     fn default() -> Self {
         Self {
-            min_size: Some((0.5.into(), 0.5.into())),
+            min_size: Some((S::from_f64(0.5).into(), S::from_f64(0.5).into())),
             max_size: None,
             style: PositionStyle::default(),
+            flex_grow: S::ONE,
+            flex_shrink: S::ONE,
+            flex_basis: SizeUnit::Auto,
+            align_self: None,
+            order: 0,
         }
     }
 }
 
 /// An element is a single node in a layout.
 #[derive(Clone, Debug)]
-pub struct Element {
+pub struct Element<S = f64> {
     /// The size of the element.
-    pub size: Vector2,
+    pub size: Vector2<S>,
     /// The position of the element.
-    pub position: Vector2,
-    /// The size of the element. This value does not change in flexible layouts.
-    pub real_size: Vector2,
-    /// The real position of the element.
-    pub real_position: Vector2,
+    pub position: Vector2<S>,
+    /// The size of the element as it was authored. This value does not change in
+    /// flexible layouts, and relative units are kept as-is so they can be resolved
+    /// again whenever the containing layout changes size.
+    pub real_size: Vector2<S>,
+    /// The position of the element as it was authored.
+    pub real_position: Vector2<S>,
+    /// If the element didn't fit in its layout, and was dealt with by the layout's
+    /// [`Overflow`](super::Overflow) policy.
+    pub overflowed: bool,
+    /// The row the element was placed in by its layout, counting from the first.
+    pub(crate) row: usize,
     /// Specific elements which change the behavior of the element in layouts.
-    pub attrs: ElementAttributes,
+    pub attrs: ElementAttributes<S>,
     /// The sub-layout of the element.
-    pub sublayout: Layout,
+    pub sublayout: Layout<S>,
 }
 
-impl Element {
+impl<S: Scalar> Element<S> {
     /// Create a new [`Element`].
-    pub fn new(size: Vector2, position: Vector2, display: LayoutType) -> Self {
+    pub fn new(size: Vector2<S>, position: Vector2<S>, display: LayoutType) -> Self {
         Self {
-            size,
-            position,
+            size: size.clone(),
+            position: position.clone(),
             real_size: size.clone(),
-            real_position: position.clone(),
+            real_position: position,
+            overflowed: false,
+            row: 0,
             attrs: ElementAttributes::default(),
             sublayout: Layout::new(display, size),
         }
     }
 
+    /// The authored size of the element along the given direction.
+    pub(crate) fn real_size_on(&self, direction: &Direction) -> SizeUnit<S> {
+        match direction {
+            Direction::X => self.real_size.0.clone(),
+            Direction::Y => self.real_size.1.clone(),
+        }
+    }
+
+    /// The computed size of the element along the given direction, in pixels.
+    pub(crate) fn size_on(&self, direction: &Direction) -> S {
+        match direction {
+            Direction::X => self.size.0.clone().pixels(),
+            Direction::Y => self.size.1.clone().pixels(),
+        }
+    }
+
+    /// The size of the element, given in the physical units (millimetres, points or
+    /// inches) it was authored in. Other units are given in pixels.
+    pub fn physical_size(&self) -> Vector2<S> {
+        let units = self.sublayout.units();
+        (
+            self.real_size
+                .0
+                .convert_pixels(self.size.0.clone().pixels(), &units),
+            self.real_size
+                .1
+                .convert_pixels(self.size.1.clone().pixels(), &units),
+        )
+    }
+
+    /// The position of the element, given in the physical units it was authored in.
+    /// Other units are given in pixels.
+    pub fn physical_position(&self) -> Vector2<S> {
+        let units = self.sublayout.units();
+        (
+            self.real_position
+                .0
+                .convert_pixels(self.position.0.clone().pixels(), &units),
+            self.real_position
+                .1
+                .convert_pixels(self.position.1.clone().pixels(), &units),
+        )
+    }
+
     /// Move the element.
-    pub fn goto(&mut self, to: Vector2) {
+    pub fn goto(&mut self, to: Vector2<S>) {
         self.position = to;
     }
 
     /// Change the size of the element.
-    pub fn resize(&mut self, to: Vector2) {
+    ///
+    /// The element's sublayout is recalculated when the size changes, so any
+    /// relative units inside of it are resolved against the new size.
+    pub fn resize(&mut self, to: Vector2<S>) {
+        let changed = self.size != to;
         self.size = to;
-        self.sublayout.size = self.size;
+        self.sublayout.size = self.size.clone();
+
+        if changed {
+            self.sublayout.recalculate();
+        }
     }
 
     /// Render as HTML for testing.
     pub fn html(&self) -> String {
         let layout_html = self.sublayout.html();
+        let (left, top) = self.physical_position();
+        let (width, height) = self.physical_size();
         format!(
-            "<layout style=\"border: inset 1px red; position: absolute; left: {}px; top: {}px; width: {}px; height: {}px;\">{layout_html}</layout>",
-            self.position.0, self.position.1, self.size.0, self.size.1
+            "<layout style=\"border: inset 1px red; position: absolute; left: {left}; top: {top}; width: {width}; height: {height};\">{layout_html}</layout>"
         )
     }
 }
@@ -1,114 +1,54 @@
-use crate::{unit, unitf};
+use crate::unitf;
 
 use super::{
-    AlignmentX, Layout, LayoutType,
+    AlignmentX, Direction, Justify, Layout, LayoutType,
     element::{Element, PositionStyle, Vector2},
+    scalar::Scalar,
     unit::SizeUnit,
 };
 
-#[derive(PartialEq, Eq)]
-pub enum Direction {
-    X,
-    Y,
-}
-
 /// A flexible layout attempts to shrink elements within it on overflow.
 ///
-/// `amount to shrink each element = overflow amount / number of elements`
-impl Layout {
-    /// Perform element resize calculations.
-    pub fn resize_flexible(&mut self, direction: Direction) -> () {
+/// `amount to shrink each element = overflow amount * shrink * basis / sum of (shrink * basis)`
+///
+/// Free space is handed out in proportion to each element's grow factor.
+///
+/// Elements sized with [`SizeUnit::Fraction`] are given whatever space is left over
+/// once every other element has taken its own, in proportion to their weights.
+impl<S: Scalar> Layout<S> {
+    /// Perform element resize calculations along the layout's main axis (see
+    /// [`Layout::direction`]).
+    pub fn resize_flexible(&mut self) {
         if self.variant != LayoutType::Flexible {
             panic!("cannot run size calculations outside of a flexible layout");
         }
 
-        let boundary = if direction == Direction::X {
-            self.size.0
-        } else {
-            self.size.1
-        };
-
-        // align x
-        if self.properties.align_x == AlignmentX::Center {
-            let mut spacing_from_center: Vec<f64> = Vec::new(); // the offset of each element from each other's positions BEFORE moving
-            let center_value = if direction == Direction::X {
-                self.size.0 / 2.0
-            } else {
-                self.size.1 / 2.0
-            };
-
-            let center_idx = self.inner.len() / 2;
-
-            // get the spacing of every other element from the center element
-            let center = self.inner.get(center_idx);
-            if let Some(center) = center {
-                let center_pos_value = if direction == Direction::X {
-                    center.position.0
-                } else {
-                    center.position.1
-                };
-
-                for (i, element) in self.inner.iter().enumerate() {
-                    let position_value = if direction == Direction::X {
-                        element.position.0
-                    } else {
-                        element.position.1
-                    };
-
-                    spacing_from_center.insert(i, unitf!(center_pos_value - position_value));
-                }
-            }
-
-            // get center element
-            let len = self.inner.len();
-            let center = self.inner.get_mut(center_idx);
-            if let Some(center) = center {
-                // move center element to the center
-                let size_value = if direction == Direction::X {
-                    center.size.0
-                } else {
-                    center.size.1
-                };
+        self.in_visual_order(Self::flex);
+    }
 
-                let mut center_new_pos = center.position.clone();
+    /// Perform element resize calculations, with the elements already sorted into
+    /// the order they are placed in.
+    fn flex(&mut self) {
+        let direction = self.direction;
 
-                if len % 2 == 0 {
-                    // even number of elements
-                    if direction == Direction::X {
-                        center_new_pos.0 = (SizeUnit::from(center_value) - size_value).into();
-                    } else {
-                        center_new_pos.1 = (SizeUnit::from(center_value) - size_value).into();
-                    }
-                } else {
-                    // odd number of elements
-                    if direction == Direction::X {
-                        center_new_pos.0 = (center_value - (size_value / 2.0)).into();
-                    } else {
-                        center_new_pos.1 = (center_value - (size_value / 2.0)).into();
-                    }
-                }
+        // only wrapping is kept from the last calculation, since elements may fit now
+        for element in self.inner.iter_mut() {
+            element.overflowed = element.row > 0;
+        }
 
-                center.goto(center_new_pos);
+        let boundary: SizeUnit<S> = direction.of(&self.content_box()).into();
 
-                // adjust all other elements
-                for (i, element) in self.inner.iter_mut().enumerate() {
-                    // get element center offset idx
-                    let center_offset = spacing_from_center.get(i).unwrap();
-                    let mut new_pos = element.position.clone();
+        // everything below works from the start of the main axis, inside of the padding
+        let (padding_start, _) = self.padding_edges();
+        self.translate((-padding_start.0, -padding_start.1));
 
-                    if direction == Direction::X {
-                        new_pos.0 = center_new_pos.0 + unit!(center_offset.to_owned());
-                    } else {
-                        new_pos.1 = center_new_pos.1 + unit!(center_offset.to_owned());
-                    }
+        self.reverse_flow();
 
-                    element.goto(new_pos);
-                }
-            }
-        }
+        self.apply_bases(direction);
 
         // shrink
-        let mut overflowing_pixels: f64 = 0.0;
+        let offset = self.offset(direction);
+        let mut overflowing_pixels = S::ZERO;
         let mut is_first_overflowing: bool = true;
 
         for element in self.inner.iter() {
@@ -117,203 +57,529 @@ impl Layout {
             }
 
             let size_value = if direction == Direction::X {
-                element.size.0
+                element.size.0.clone()
             } else {
-                element.size.1
+                element.size.1.clone()
             };
 
             let position_value = if direction == Direction::X {
-                element.position.0
+                element.position.0.clone()
             } else {
-                element.position.1
+                element.position.1.clone()
             };
 
-            if position_value + size_value + self.properties.offset > boundary.into() {
+            if unitf!(position_value.clone() + size_value.clone()) > unitf!(boundary.clone()) {
                 if is_first_overflowing {
                     // the first element to overflow is slightly more complicated
                     // because some of it is likely not overflowing... this means
                     // we need to calculate how much is ACTUALLY outside
-                    overflowing_pixels += unitf!((boundary - (size_value + position_value)).abs());
+                    overflowing_pixels +=
+                        unitf!((boundary.clone() - (size_value + position_value)).abs());
                     is_first_overflowing = false;
                 } else {
                     // everything else is guaranteed to be 100% outside of the box
-                    overflowing_pixels += size_value + self.properties.offset;
+                    overflowing_pixels += unitf!(size_value + offset);
+                }
+            }
+        }
+
+        // elements sized in fractions only ever receive leftover space, so their
+        // fixed siblings are the only ones which shrink
+        let shrink_weights: Vec<S> = self
+            .inner
+            .iter()
+            .map(|e| {
+                if e.attrs.style == PositionStyle::Absolute
+                    || e.real_size_on(&direction).is_fraction()
+                {
+                    S::ZERO
+                } else {
+                    e.attrs.flex_shrink * e.size_on(&direction)
+                }
+            })
+            .collect();
+
+        if overflowing_pixels > S::ZERO {
+            // elements which keep their size are still kept within their limits
+            let (amount, weights) = if self.keeps_sizes() {
+                (S::ZERO, vec![S::ZERO; self.inner.len()])
+            } else {
+                (-overflowing_pixels, shrink_weights)
+            };
+
+            let deltas = self.flex_deltas(amount, &weights, direction, None);
+            let limits = self.limit_deltas(direction);
+
+            for ((element, delta), limit) in self.inner.iter_mut().zip(&deltas).zip(&limits) {
+                // every element which shrank to make the rest fit overflowed, but
+                // clamping one down to its maximum size doesn't count
+                if *delta < *limit {
+                    element.overflowed = true;
+                }
+            }
+
+            self.apply_deltas(&direction, &deltas);
+        } else {
+            // grow
+            self.grow(direction, self.properties.flex_grow);
+        }
+
+        self.align_x(direction);
+        self.justify(direction);
+
+        self.reverse_flow();
+
+        self.translate(padding_start);
+        self.mark_overflowing();
+
+        if self.units().pixel_snap {
+            self.snap_to_pixels();
+        }
+    }
+
+    /// Hand out the space left over along the given direction, one row at a time.
+    /// Elements sized in fractions take all of their row's space when there are any,
+    /// otherwise it goes to every element in the row in proportion to its grow factor,
+    /// as long as `flex_grow` is set.
+    pub(crate) fn grow(&mut self, direction: Direction, flex_grow: bool) {
+        // the good news here is this is the same thing as shrinking, just we
+        // calculate how many pixels we can expand to, and then add instead of subtract
+        // from each element!
+        let boundary = direction.of(&self.content_box());
+        let offset = self.offset(direction);
+
+        let mut deltas = vec![S::ZERO; self.inner.len()];
+
+        for row in self.rows() {
+            let mut extra_pixels = S::ZERO;
+            let mut total_fractions = S::ZERO;
+
+            for (n, &i) in row.iter().enumerate() {
+                let element = &self.inner[i];
+
+                // the space between elements can't be grown into
+                if n > 0 {
+                    extra_pixels += offset;
                 }
+
+                if let SizeUnit::Fraction(weight) = element.real_size_on(&direction) {
+                    total_fractions += weight;
+                }
+
+                extra_pixels += element.size_on(&direction);
+            }
+
+            // nothing grows when the row overflows, but the elements are still kept
+            // within their limits
+            extra_pixels = (boundary - extra_pixels).max(S::ZERO);
+
+            let mut grow_weights = vec![S::ZERO; self.inner.len()];
+            for &i in row.iter() {
+                let element = &self.inner[i];
+
+                grow_weights[i] = if total_fractions > S::ZERO {
+                    // fixed elements have already taken their space, so everything
+                    // left over goes to fractions (in proportion to their weight)
+                    match element.real_size_on(&direction) {
+                        SizeUnit::Fraction(weight) => weight,
+                        _ => S::ZERO,
+                    }
+                } else if flex_grow {
+                    element.attrs.flex_grow
+                } else {
+                    S::ZERO
+                };
+            }
+
+            let row_deltas = self.flex_deltas(
+                extra_pixels,
+                &grow_weights,
+                direction,
+                Some(self.inner[row[0]].row),
+            );
+            for &i in row.iter() {
+                deltas[i] = row_deltas[i];
             }
         }
 
-        let amount_to_shrink_all_elements = overflowing_pixels / self.inner.len() as f64;
+        self.apply_deltas(&direction, &deltas);
+    }
+
+    /// Move every element along the given direction so the group of them follows
+    /// [`AlignmentX`], keeping the space between each element as it is.
+    fn align_x(&mut self, direction: Direction) {
+        if self.properties.align_x == AlignmentX::Left {
+            return;
+        }
 
-        for (i, element) in self.inner.iter_mut().enumerate() {
+        let boundary = direction.of(&self.content_box());
+
+        // the bounding box of every relative element
+        let mut bounds: Option<(S, S)> = None;
+        for element in self.inner.iter() {
             if element.attrs.style == PositionStyle::Absolute {
                 continue;
             }
 
-            let mut new_size = element.size.clone();
-
-            if direction == Direction::X {
-                new_size.0 -= amount_to_shrink_all_elements;
+            let start = if direction == Direction::X {
+                unitf!(element.position.0.clone())
             } else {
-                new_size.1 -= amount_to_shrink_all_elements;
+                unitf!(element.position.1.clone())
             };
+            let end = start + element.size_on(&direction);
 
-            element.resize(new_size);
+            bounds = Some(match bounds {
+                Some((min, max)) => (min.min(start), max.max(end)),
+                None => (start, end),
+            });
+        }
 
-            let position_value = if direction == Direction::X {
-                element.position.0
+        let Some((min, max)) = bounds else {
+            return;
+        };
+
+        let shift = match self.properties.align_x {
+            AlignmentX::Left => S::ZERO,
+            AlignmentX::Center => (boundary - (max - min)) / S::from_f64(2.0) - min,
+            AlignmentX::End => boundary - max,
+        };
+
+        // centering can land between cells
+        let shift = self.units().snap(shift, direction);
+
+        for element in self.inner.iter_mut() {
+            if element.attrs.style == PositionStyle::Absolute {
+                continue;
+            }
+
+            let mut new_pos = element.position.clone();
+            if direction == Direction::X {
+                new_pos.0 += shift;
             } else {
-                element.position.1
+                new_pos.1 += shift;
+            }
+
+            element.goto(new_pos);
+        }
+    }
+
+    /// Share out the space left at the end of each row, following [`Justify`].
+    fn justify(&mut self, direction: Direction) {
+        if self.properties.justify == Justify::Start {
+            return;
+        }
+
+        let boundary = direction.of(&self.content_box());
+
+        let offset = self.offset(direction);
+        let units = self.units();
+
+        for row in self.rows() {
+            let count = S::from_f64(row.len() as f64);
+            let used = row
+                .iter()
+                .fold(S::ZERO, |sum, &i| sum + self.inner[i].size_on(&direction))
+                + offset * (count - S::ONE);
+            let free = boundary - used;
+
+            if free <= S::ZERO {
+                continue;
+            }
+
+            let two = S::from_f64(2.0);
+            let (lead, between) = match self.properties.justify {
+                Justify::Start => (S::ZERO, S::ZERO),
+                Justify::End => (free, S::ZERO),
+                Justify::Center => (free / two, S::ZERO),
+                Justify::SpaceBetween if row.len() > 1 => (S::ZERO, free / (count - S::ONE)),
+                Justify::SpaceBetween => (S::ZERO, S::ZERO),
+                Justify::SpaceAround => (free / count / two, free / count),
+                Justify::SpaceEvenly => (free / (count + S::ONE), free / (count + S::ONE)),
             };
 
-            if position_value != 0.0 {
+            let mut position = lead;
+            for i in row {
+                let element = &mut self.inner[i];
                 let mut new_pos = element.position.clone();
-                let x = amount_to_shrink_all_elements * if i > 1 { i as f64 } else { 1.0 };
 
                 if direction == Direction::X {
-                    new_pos.0 -= x;
+                    new_pos.0 = units.snap(position, direction).into();
                 } else {
-                    new_pos.1 -= x;
-                };
+                    new_pos.1 = units.snap(position, direction).into();
+                }
 
+                position += element.size_on(&direction) + offset + between;
                 element.goto(new_pos);
             }
         }
+    }
 
-        // grow
-        // the good news here is this is the same thing as shrinking, just we
-        // calculate how many pixels we can expand to, and then add instead of subtract
-        // from each element!
-        if self.properties.flex_grow && overflowing_pixels == 0.0 {
-            // nothing is overflowing, that means there is extra room waiting to be used!
-            let mut extra_pixels: f64 = 0.0;
+    /// Size every element with a flex basis to that basis along the given direction,
+    /// then place the elements again.
+    fn apply_bases(&mut self, direction: Direction) {
+        let parent = direction.of(&self.content_box());
 
-            for element in self.inner.iter() {
-                if element.attrs.style == PositionStyle::Absolute {
-                    continue;
-                }
+        let units = self.units();
+        let mut changed = false;
 
-                let size_value = if direction == Direction::X {
-                    element.size.0
-                } else {
-                    element.size.1
-                };
+        for element in self.inner.iter_mut() {
+            if element.attrs.flex_basis.is_auto() {
+                continue;
+            }
+
+            let basis = units.resolve_snapped(&element.attrs.flex_basis, direction, parent);
+            let mut new_size = element.size.clone();
 
-                extra_pixels += <SizeUnit as Into<f64>>::into(size_value);
+            if direction == Direction::X {
+                new_size.0 = basis;
+            } else {
+                new_size.1 = basis;
             }
 
-            extra_pixels = boundary - extra_pixels;
-            let amount_to_grow_all_elements = extra_pixels / self.inner.len() as f64;
+            element.resize(new_size);
+            changed = true;
+        }
+
+        if changed {
+            // rows are placed from where elements were authored, not where they
+            // were aligned to last time
+            let (width, height) = self.content_box();
+            for element in self.inner.iter_mut() {
+                element.goto((
+                    units.resolve_snapped(&element.real_position.0, Direction::X, width),
+                    units.resolve_snapped(&element.real_position.1, Direction::Y, height),
+                ));
+            }
+
+            self.recalculate_as_flexible((S::ZERO.into(), S::ZERO.into()));
+        }
+    }
 
-            for (i, element) in self.inner.iter_mut().enumerate() {
+    /// Keep `size` within the element's minimum and maximum size along the given
+    /// direction.
+    fn clamp_to_limits(&self, element: &Element<S>, size: S, direction: Direction) -> S {
+        let parent = direction.of(&self.content_box());
+
+        let units = self.units();
+        let limit = |limits: &Option<Vector2<S>>| {
+            limits.as_ref().map(|limits| {
+                unitf!(units.resolve_snapped(&direction.of(limits), direction, parent))
+            })
+        };
+
+        let mut clamped = size;
+        if let Some(max) = limit(&element.attrs.max_size) {
+            clamped = clamped.min(max);
+        }
+
+        if let Some(min) = limit(&element.attrs.min_size) {
+            clamped = clamped.max(min);
+        }
+
+        clamped
+    }
+
+    /// How much each element has to be resized along the given direction to be within
+    /// its minimum and maximum size.
+    fn limit_deltas(&self, direction: Direction) -> Vec<S> {
+        self.inner
+            .iter()
+            .map(|element| {
                 if element.attrs.style == PositionStyle::Absolute {
-                    continue;
+                    return S::ZERO;
                 }
 
-                let mut new_size = element.size.clone();
+                let size = element.size_on(&direction);
+                self.clamp_to_limits(element, size, direction) - size
+            })
+            .collect()
+    }
 
-                if direction == Direction::X {
-                    new_size.0 += amount_to_grow_all_elements;
+    /// Split `amount` (negative when shrinking) between every element in proportion
+    /// to `weights`, keeping each element within its minimum and maximum size. Only
+    /// the elements in `row` are resized when one is given.
+    ///
+    /// Elements without a weight (and elements bigger than their maximum size) are
+    /// clamped to their limits first. Whatever a clamped element can't take after that
+    /// is split between the elements which aren't clamped yet, over and over until
+    /// every element is within its limits. Clamping can make space or use it up, but
+    /// never turns a shrink into a grow (or the other way around).
+    fn flex_deltas(
+        &self,
+        amount: S,
+        weights: &[S],
+        direction: Direction,
+        row: Option<usize>,
+    ) -> Vec<S> {
+        let skipped = |element: &Element<S>| {
+            element.attrs.style == PositionStyle::Absolute
+                || row.is_some_and(|row| element.row != row)
+        };
+
+        let same_side = |remaining: S| {
+            if amount < S::ZERO {
+                remaining.min(S::ZERO)
+            } else {
+                remaining.max(S::ZERO)
+            }
+        };
+
+        let mut deltas: Vec<S> = self
+            .inner
+            .iter()
+            .zip(weights)
+            .map(|(element, &weight)| {
+                if skipped(element) {
+                    return S::ZERO;
+                }
+
+                let size = element.size_on(&direction);
+                let clamped = self.clamp_to_limits(element, size, direction);
+
+                // elements which are resized are clamped once they have their share
+                if weight > S::ZERO && clamped >= size {
+                    S::ZERO
                 } else {
-                    new_size.1 += amount_to_grow_all_elements;
-                };
+                    clamped - size
+                }
+            })
+            .collect();
 
-                element.resize(new_size);
+        let clamped = deltas.iter().fold(S::ZERO, |sum, &delta| sum + delta);
+        let mut remaining = same_side(amount - clamped);
+        let mut weights = weights.to_vec();
 
-                if element.position.0 != 0.0 {
-                    let mut new_pos = element.position.clone();
-                    let x = amount_to_grow_all_elements * if i > 1 { i as f64 } else { 1.0 };
+        while remaining != S::ZERO {
+            let shares = self.distribute(remaining.abs(), &weights, direction);
+            let sign = if remaining < S::ZERO { -S::ONE } else { S::ONE };
+            remaining = S::ZERO;
 
-                    if direction == Direction::X {
-                        new_pos.0 += x;
-                    } else {
-                        new_pos.1 += x;
-                    };
+            for (i, element) in self.inner.iter().enumerate() {
+                if weights[i] <= S::ZERO {
+                    continue;
+                }
 
-                    element.goto(new_pos);
+                let size = element.size_on(&direction);
+                let target = size + deltas[i] + shares[i] * sign;
+                let clamped = self.clamp_to_limits(element, target, direction);
+
+                if clamped != target {
+                    // this element is as big (or small) as it can be, so the rest
+                    // of its share goes to everything else
+                    remaining += target - clamped;
+                    weights[i] = S::ZERO;
                 }
+
+                deltas[i] = clamped - size;
+            }
+
+            remaining = same_side(remaining);
+
+            if weights.iter().all(|&w| w <= S::ZERO) {
+                break;
             }
         }
+
+        deltas
     }
 
-    /// Undo resizing in a flexible layout. Should be done before adding more elements
-    /// to the layout. After all elements are added, [`Self::resize_flexible`] should
-    /// be run.
-    pub fn revert_flexible(&mut self) -> () {
-        if self.variant != LayoutType::Flexible {
-            panic!("cannot run size calculations outside of a flexible layout");
+    /// Split `amount` between every element in proportion to `weights`.
+    ///
+    /// In cell-grid mode every share is a whole number of cells, and integer (or
+    /// fixed-point) scalars can only split it into whole steps (see [`Scalar::STEP`]).
+    /// The steps lost when rounding each share down are handed out to the largest
+    /// remainders first, so the shares still add up to exactly `amount`.
+    fn distribute(&self, amount: S, weights: &[S], direction: Direction) -> Vec<S> {
+        let total = weights.iter().fold(S::ZERO, |sum, &w| sum + w);
+
+        if total <= S::ZERO {
+            return vec![S::ZERO; weights.len()];
         }
 
-        for element in self.inner.iter_mut() {
-            element.size = element.real_size.clone();
-            element.position = element.real_position.clone();
+        let units = self.units();
+        let step = if units.cell_grid {
+            units.cell(direction)
+        } else {
+            S::STEP
+        };
+
+        let Some(steps) = amount.checked_div(step) else {
+            // floats can be split exactly
+            return weights.iter().map(|&w| amount * w / total).collect();
+        };
+        let steps = steps.round();
+
+        // shares are kept multiplied by the total weight until they're rounded down, so
+        // integer scalars don't lose their remainders
+        let scaled: Vec<S> = weights.iter().map(|&w| steps * w).collect();
+        let mut shares: Vec<S> = scaled.iter().map(|&s| (s / total).floor()).collect();
+        let leftover = (steps - shares.iter().fold(S::ZERO, |sum, &share| sum + share))
+            .round()
+            .to_f64() as usize;
+
+        let remainder = |i: usize| (scaled[i] - shares[i] * total).to_f64();
+        let mut by_remainder: Vec<usize> = (0..weights.len())
+            .filter(|&i| weights[i] > S::ZERO)
+            .collect();
+        // this is a stable sort, so ties go to the earliest element
+        by_remainder.sort_by(|&a, &b| remainder(b).total_cmp(&remainder(a)));
+
+        for i in by_remainder.into_iter().take(leftover) {
+            shares[i] += S::ONE;
         }
-    }
 
-    pub(crate) fn recalculate_as_flexible(&mut self, basis: Vector2) {
-        let mut previous_element: Option<&mut Element> = None;
-        let mut previous_on_new_row: Option<Element> = None;
-        let mut tallest_of_row: f64 = 0.0;
+        shares.into_iter().map(|share| share * step).collect()
+    }
 
-        let mut cloned = self.inner.clone();
+    /// Resize each element along the given direction by its matching value in `deltas`,
+    /// moving every element after it (in the same row) along by the same amount.
+    fn apply_deltas(&mut self, direction: &Direction, deltas: &[S]) {
+        let mut shift = S::ZERO;
+        let mut previous_row = 0;
 
-        for element in cloned.iter_mut() {
+        for (element, delta) in self.inner.iter_mut().zip(deltas) {
             if element.attrs.style == PositionStyle::Absolute {
-                // should not attempt to move absolute element
                 continue;
             }
 
-            let pre = match previous_element {
-                Some(ref e) => e,
-                None => {
-                    // not really anything to adjust since this is the first element
-                    previous_element = Some(element);
-                    continue;
-                }
-            };
-
-            let mut new_pos: Vector2 = (
-                basis.0 + pre.position.0 + pre.size.0 + unit!(self.properties.offset),
-                (basis.1 + pre.position.1).into(), // we're going to stay on the same y level (unless we overflow)
-            );
-
-            if (new_pos.0 + element.size.0 + self.properties.offset) > self.size.0.into()
-                && self.col
-            {
-                // do wrap
-                let first_of_row = if let Some(ref e) = previous_on_new_row {
-                    // this means that this is not the first time we're going on a new row
-                    // this should be a clone of the first element of the current row (that is full)...
-                    // this is not a mutable reference, so it isn't to be edited
-                    e
-                } else {
-                    self.inner.get(0).unwrap()
-                };
-
-                if tallest_of_row == 0.0 {
-                    tallest_of_row = first_of_row.size.1.into();
-                }
+            let mut new_size = element.size.clone();
+            let mut new_pos = element.position.clone();
 
-                // we're doing tallest height + first.position.1(y) so that we're
-                // under the first element AND under the tallest element
-                new_pos = (
-                    0.0.into(),
-                    (tallest_of_row + unitf!(first_of_row.position.1) + self.properties.offset)
-                        .into(),
-                );
-                previous_on_new_row = Some(element.clone());
-                tallest_of_row = element.size.1.into(); // this is the first element in the new row, so it is the tallest
+            if element.row != previous_row {
+                // we've gone back to the start of a new row, nothing before us in
+                // this row has moved
+                shift = S::ZERO;
             }
 
-            if unitf!(element.size.1) > tallest_of_row {
-                tallest_of_row = element.size.1.into();
-            }
+            previous_row = element.row;
 
-            element.real_position = new_pos.clone();
+            if direction == &Direction::X {
+                new_size.0 += *delta;
+                new_pos.0 += shift;
+            } else {
+                new_size.1 += *delta;
+                new_pos.1 += shift;
+            };
+
+            shift += *delta;
+            element.resize(new_size);
             element.goto(new_pos);
-            previous_element = Some(element);
         }
+    }
+
+    /// Undo resizing in a flexible layout. Should be done before adding more elements
+    /// to the layout. After all elements are added, [`Self::resize_flexible`] should
+    /// be run.
+    pub fn revert_flexible(&mut self) {
+        if self.variant != LayoutType::Flexible {
+            panic!("cannot run size calculations outside of a flexible layout");
+        }
+
+        // real sizes may be relative, so they need to be resolved again
+        self.recalculate();
+    }
 
-        // ...
-        self.inner = cloned;
+    pub(crate) fn recalculate_as_flexible(&mut self, basis: Vector2<S>) {
+        self.place_in_rows(basis);
     }
 }
@@ -1,9 +1,16 @@
 pub mod block;
 pub mod element;
+pub mod fixed;
 pub mod flexible;
+pub mod parse;
+pub mod scalar;
 pub mod unit;
 
-use element::{Element, Vector2};
+use crate::{unit, unitf};
+
+use element::{Element, PositionStyle, Vector2};
+use scalar::Scalar;
+use unit::{SizeUnit, UnitContext};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum LayoutType {
@@ -13,91 +20,855 @@ pub enum LayoutType {
     Block,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// An axis of a layout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Direction {
+    #[default]
+    X,
+    Y,
+}
+
+impl Direction {
+    /// The direction perpendicular to this one.
+    pub fn cross(self) -> Self {
+        match self {
+            Self::X => Self::Y,
+            Self::Y => Self::X,
+        }
+    }
+
+    /// The part of `vector` along this direction.
+    pub(crate) fn of<T: Clone>(self, vector: &(T, T)) -> T {
+        match self {
+            Self::X => vector.0.clone(),
+            Self::Y => vector.1.clone(),
+        }
+    }
+
+    /// A mutable reference to the part of `vector` along this direction.
+    pub(crate) fn of_mut<T>(self, vector: &mut (T, T)) -> &mut T {
+        match self {
+            Self::X => &mut vector.0,
+            Self::Y => &mut vector.1,
+        }
+    }
+}
+
+/// Where the group of elements in a flexible layout sits along the direction it is
+/// resized in.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub enum AlignmentX {
+    #[default]
     Left,
     Center,
+    End,
 }
 
-impl Default for AlignmentX {
-    fn default() -> Self {
-        Self::Left
-    }
+/// Where elements sit within the height of their row (or the width of their column,
+/// in a vertical flexible layout).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum AlignmentY {
+    #[default]
+    Top,
+    Center,
+    Bottom,
+    /// Grow to the height of the row, up to the element's maximum height.
+    Stretch,
 }
 
+/// How free space is shared out along the main axis of a flexible layout, once every
+/// element has grown as much as it can.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Justify {
+    /// Packed at the start of the row.
+    #[default]
+    Start,
+    /// Packed at the end of the row.
+    End,
+    /// Packed in the middle of the row.
+    Center,
+    /// The first and last elements touch the edges, and the space is split between them.
+    SpaceBetween,
+    /// Every element has the same space on both sides of it.
+    SpaceAround,
+    /// The space between elements and the edges is all the same.
+    SpaceEvenly,
+}
+
+/// What a layout does with elements which don't fit inside of it. Elements which are
+/// affected are marked as [`Element::overflowed`].
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub enum AlignmentY {
-    Top,
+pub enum Overflow {
+    /// Move elements onto a new row.
+    Wrap,
+    /// Shrink elements in flexible layouts until they fit. Block layouts never
+    /// resize elements, so they are clipped instead.
+    Shrink,
+    /// Keep every element's size, and report the region they should be clipped to
+    /// (see [`Layout::clip_region`]).
+    Clip,
+    /// Keep every element's size, so the contents can be scrolled through (see
+    /// [`Layout::content_size`]).
+    Scroll,
+    /// Drop the elements which don't fit. They keep their index, but aren't exported
+    /// or counted in the content size.
+    Hide,
+}
+
+/// How the space left over along the cross axis is shared between the rows of a
+/// wrapping layout.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum AlignContent {
+    /// Rows are packed at the start.
+    #[default]
+    Start,
+    /// Rows are packed in the middle.
     Center,
+    /// Rows are packed at the end.
+    End,
+    /// The first and last rows touch the edges, and the space is split between them.
+    SpaceBetween,
+    /// Every row has the same space on both sides of it.
+    SpaceAround,
+    /// Every row grows by the same amount, which elements aligned with
+    /// [`AlignmentY::Stretch`] grow into.
+    Stretch,
+}
+
+/// Spacing on each side of a layout. Left and right are relative to the layout's
+/// width, top and bottom to its height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Padding<S = f64> {
+    pub top: SizeUnit<S>,
+    pub right: SizeUnit<S>,
+    pub bottom: SizeUnit<S>,
+    pub left: SizeUnit<S>,
+}
+
+impl<S: Scalar> Padding<S> {
+    /// The same padding on every side.
+    pub fn all(value: SizeUnit<S>) -> Self {
+        Self {
+            top: value.clone(),
+            right: value.clone(),
+            bottom: value.clone(),
+            left: value,
+        }
+    }
 }
 
-impl Default for AlignmentY {
+impl<S: Scalar> Default for Padding<S> {
     fn default() -> Self {
-        Self::Top
+        Self::all(S::ZERO.into())
+    }
+}
+
+impl<S: Scalar> From<SizeUnit<S>> for Padding<S> {
+    fn from(value: SizeUnit<S>) -> Self {
+        Self::all(value)
     }
 }
 
 #[derive(Clone, Debug)]
-pub struct LayoutProperties {
-    /// The spacing between each element.
-    pub offset: f64,
-    /// The spacing between each element and the layout bounding box.
-    pub padding: f64,
-    /// The horizontal alignment of elements in flexible layouts.
+pub struct LayoutProperties<S = f64> {
+    /// The spacing between each element, unless [`Self::row_gap`] or
+    /// [`Self::column_gap`] override it.
+    pub offset: SizeUnit<S>,
+    /// The vertical spacing between rows.
+    pub row_gap: Option<SizeUnit<S>>,
+    /// The horizontal spacing between elements in the same row (or between columns,
+    /// in a vertical flexible layout).
+    pub column_gap: Option<SizeUnit<S>>,
+    /// The spacing between each element and the layout bounding box. Elements are
+    /// placed inside of it, but absolutely positioned elements ignore it.
+    pub padding: Padding<S>,
+    /// The alignment of elements in flexible layouts, along the direction they are resized in.
     pub align_x: AlignmentX,
-    /// The verticle alignment of elements in flexible layouts.
+    /// The vertical alignment of elements within their row.
     pub align_y: AlignmentY,
     /// If elements in a flexible container automatically grow to fill the container.
     pub flex_grow: bool,
+    /// How the space left over in a flexible layout is shared out between elements.
+    pub justify: Justify,
+    /// How the space left over along the cross axis is shared out between rows.
+    pub align_content: AlignContent,
+    /// The font size used to resolve [`SizeUnit::Em`] within this layout (and any
+    /// sublayouts which don't set their own). Inherited from the parent layout if `None`.
+    pub font_size: Option<SizeUnit<S>>,
 }
 
-impl Default for LayoutProperties {
+impl<S: Scalar> Default for LayoutProperties<S> {
     fn default() -> Self {
         Self {
-            offset: 0.0,
-            padding: 0.0,
+            offset: S::ZERO.into(),
+            row_gap: None,
+            column_gap: None,
+            padding: Padding::default(),
             align_x: AlignmentX::default(),
             align_y: AlignmentY::default(),
             flex_grow: true,
+            justify: Justify::default(),
+            align_content: AlignContent::default(),
+            font_size: None,
         }
     }
 }
 
 #[derive(Clone, Debug)]
-pub struct Layout {
+pub struct Layout<S = f64> {
     /// The elements contained within the layout.
-    pub(crate) inner: Vec<Element>,
+    pub(crate) inner: Vec<Element<S>>,
     /// The variant of layout this iss.
     pub variant: LayoutType,
     /// The size of the containing element.
-    pub size: Vector2,
-    /// If the flexible layout should wrap elements instead of shrinking.
-    /// This essentially creates a column layout.
-    pub col: bool,
+    pub size: Vector2<S>,
+    /// What happens to elements which don't fit in the layout. Block layouts wrap
+    /// by default, and flexible layouts shrink.
+    ///
+    /// Block layouts never resize elements, so [`Overflow::Shrink`] clips them instead,
+    /// exactly like [`Overflow::Clip`].
+    pub overflow: Overflow,
+    /// The main axis of a flexible layout, which elements are placed along. Rows
+    /// wrap along the other axis.
+    pub direction: Direction,
+    /// If elements are placed from the far end of the main axis, so the first element
+    /// sits at the right (or bottom) edge. Rows still wrap in the usual direction.
+    pub reverse: bool,
+    /// If rows stack upward from the bottom edge (or leftward from the right edge, in
+    /// a vertical flexible layout) instead of downward. Alignment within each row and
+    /// between rows is flipped along with them, like CSS `wrap-reverse`.
+    pub wrap_reverse: bool,
+    /// The number of pixels in an inch, used to convert physical units (millimetres,
+    /// points and inches) into pixels. Only read on the root layout.
+    pub dpi: f64,
+    /// The size of a single terminal cell, used by [`SizeUnit::Cell`]. Only read
+    /// on the root layout.
+    pub cell_size: (S, S),
+    /// If every size and position should be snapped to whole cells, for laying out
+    /// terminal interfaces. Only read on the root layout.
+    pub cell_grid: bool,
+    /// The number of device pixels in each pixel of the layout. Only read on the
+    /// root layout.
+    pub scale_factor: f64,
+    /// If the edges of every element should be rounded to whole device pixels once
+    /// the layout is calculated (see [`Self::snap_to_pixels`]). Only read on the
+    /// root layout.
+    pub pixel_snap: bool,
     /// Layout configuration.
-    pub properties: LayoutProperties,
+    pub properties: LayoutProperties<S>,
+    /// The unit context passed down by the parent layout. `None` for root layouts.
+    pub(crate) inherited: Option<UnitContext<S>>,
 }
 
-impl Layout {
+impl<S: Scalar> Layout<S> {
     /// Create a new [`Layout`].
-    pub fn new(r#type: LayoutType, size: Vector2) -> Self {
+    pub fn new(r#type: LayoutType, size: Vector2<S>) -> Self {
+        let overflow = match r#type {
+            LayoutType::Flexible => Overflow::Shrink,
+            LayoutType::Block => Overflow::Wrap,
+        };
+
         Self {
             inner: Vec::new(),
             variant: r#type,
             size,
-            col: false,
+            overflow,
+            direction: Direction::default(),
+            reverse: false,
+            wrap_reverse: false,
+            dpi: 96.0,
+            cell_size: (S::ONE, S::ONE),
+            cell_grid: false,
+            scale_factor: 1.0,
+            pixel_snap: false,
             properties: LayoutProperties::default(),
+            inherited: None,
         }
     }
 
     /// Recalculate element sizes/positions using the correct calculator for the layout type.
-    pub fn recalculate(&mut self) -> () {
+    ///
+    /// Nothing is calculated while the layout's own size is still relative (for
+    /// example, a percentage that hasn't been resolved by a parent layout yet).
+    pub fn recalculate(&mut self) {
+        if !self.size.0.is_resolved() || !self.size.1.is_resolved() {
+            return;
+        }
+
+        self.resolve_units();
+
+        self.in_visual_order(|layout| {
+            for element in layout.inner.iter_mut() {
+                element.overflowed = false;
+            }
+
+            match layout.variant {
+                LayoutType::Flexible => {
+                    layout.recalculate_as_flexible((S::ZERO.into(), S::ZERO.into()));
+                    // fractions are sized from the space left over, which is only
+                    // known once everything else has been placed
+                    layout.grow(layout.direction, false);
+                }
+                LayoutType::Block => layout.recalculate_as_block(),
+            }
+
+            layout.reverse_flow();
+
+            // elements are placed as if the padding wasn't there, then moved inside of it
+            layout.translate(layout.padding_edges().0);
+        });
+
+        self.mark_overflowing();
+
+        if self.units().pixel_snap {
+            self.snap_to_pixels();
+        }
+    }
+
+    /// If elements which don't fit are left where they are (and clipped, scrolled or
+    /// hidden), instead of being wrapped or shrunk.
+    pub(crate) fn keeps_sizes(&self) -> bool {
+        match self.overflow {
+            Overflow::Wrap => false,
+            Overflow::Shrink => self.variant == LayoutType::Block,
+            Overflow::Clip | Overflow::Scroll | Overflow::Hide => true,
+        }
+    }
+
+    /// Mark every element which reaches outside of the padding as overflowed, when the
+    /// layout keeps element sizes.
+    pub(crate) fn mark_overflowing(&mut self) {
+        if !self.keeps_sizes() {
+            return;
+        }
+
+        let (start, _) = self.padding_edges();
+        let (width, height) = self.content_box();
+        let end = (start.0 + width, start.1 + height);
+
+        for element in self.inner.iter_mut() {
+            if element.attrs.style == PositionStyle::Absolute {
+                continue;
+            }
+
+            let x = unitf!(element.position.0.clone());
+            let y = unitf!(element.position.1.clone());
+            element.overflowed = x < start.0
+                || y < start.1
+                || x + element.size_on(&Direction::X) > end.0
+                || y + element.size_on(&Direction::Y) > end.1;
+        }
+    }
+
+    /// If the element at the given index was dropped by [`Overflow::Hide`].
+    pub fn is_hidden(&self, idx: usize) -> bool {
+        self.overflow == Overflow::Hide && self.inner.get(idx).is_some_and(|e| e.overflowed)
+    }
+
+    /// The indices of every element which didn't fit in the layout, and was wrapped,
+    /// shrunk, clipped or hidden because of it.
+    pub fn overflowed(&self) -> Vec<usize> {
+        self.inner
+            .iter()
+            .enumerate()
+            .filter(|(_, element)| element.overflowed)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// The region (position and size, in pixels) elements should be clipped to, when
+    /// the layout clips its overflow.
+    pub fn clip_region(&self) -> Option<((S, S), (S, S))> {
+        if !self.keeps_sizes() || self.overflow == Overflow::Scroll {
+            return None;
+        }
+
+        Some((self.padding_edges().0, self.content_box()))
+    }
+
+    /// Run `calculate` with the elements sorted by their `order` (see
+    /// [`ElementAttributes::order`](element::ElementAttributes::order)), then put them
+    /// back in the order they were added so their indices don't change.
+    pub(crate) fn in_visual_order(&mut self, calculate: impl FnOnce(&mut Self)) {
+        let mut indices: Vec<usize> = (0..self.inner.len()).collect();
+        // this is a stable sort, so elements with the same order stay in insertion order
+        indices.sort_by_key(|&i| self.inner[i].attrs.order);
+
+        if indices.is_sorted() {
+            calculate(self);
+            return;
+        }
+
+        let mut elements: Vec<Option<Element<S>>> = std::mem::take(&mut self.inner)
+            .into_iter()
+            .map(Some)
+            .collect();
+        self.inner = indices
+            .iter()
+            .map(|&i| elements[i].take().unwrap())
+            .collect();
+
+        calculate(self);
+
+        let mut restored: Vec<Option<Element<S>>> = indices.iter().map(|_| None).collect();
+        for (element, &i) in std::mem::take(&mut self.inner).into_iter().zip(&indices) {
+            restored[i] = Some(element);
+        }
+
+        self.inner = restored.into_iter().map(Option::unwrap).collect();
+    }
+
+    /// The padding on the (left, top) and (right, bottom) edges of the layout, in pixels.
+    pub(crate) fn padding_edges(&self) -> ((S, S), (S, S)) {
+        let (width, height) = (unitf!(self.size.0.clone()), unitf!(self.size.1.clone()));
+        let units = self.units();
+        let padding = &self.properties.padding;
+        let x = |unit| unitf!(units.resolve_snapped(unit, Direction::X, width));
+        let y = |unit| unitf!(units.resolve_snapped(unit, Direction::Y, height));
+
+        (
+            (x(&padding.left), y(&padding.top)),
+            (x(&padding.right), y(&padding.bottom)),
+        )
+    }
+
+    /// The size of the layout inside of its padding, which elements are placed in.
+    pub fn content_box(&self) -> (S, S) {
+        let (start, end) = self.padding_edges();
+        (
+            (unitf!(self.size.0.clone()) - start.0 - end.0).max(S::ZERO),
+            (unitf!(self.size.1.clone()) - start.1 - end.1).max(S::ZERO),
+        )
+    }
+
+    /// Move every relatively positioned element by the given amount.
+    pub(crate) fn translate(&mut self, by: (S, S)) {
+        if by == (S::ZERO, S::ZERO) {
+            return;
+        }
+
+        for element in self.inner.iter_mut() {
+            if element.attrs.style == PositionStyle::Absolute {
+                continue;
+            }
+
+            element.goto((
+                element.position.0.clone() + unit!(by.0),
+                element.position.1.clone() + unit!(by.1),
+            ));
+        }
+    }
+
+    /// The axis elements are placed along. Block layouts are always horizontal.
+    pub(crate) fn main_axis(&self) -> Direction {
         match self.variant {
-            LayoutType::Flexible => self.recalculate_as_flexible((0.0.into(), 0.0.into())),
-            LayoutType::Block => self.recalculate_as_block(),
+            LayoutType::Flexible => self.direction,
+            LayoutType::Block => Direction::X,
         }
     }
 
+    /// Mirror every element along the given axis, so elements placed from the start
+    /// of it are placed from the end instead (and back again).
+    pub(crate) fn mirror(&mut self, axis: Direction) {
+        let boundary = axis.of(&self.content_box());
+
+        for element in self.inner.iter_mut() {
+            if element.attrs.style == PositionStyle::Absolute {
+                continue;
+            }
+
+            let mut new_pos = element.position.clone();
+            *axis.of_mut(&mut new_pos) =
+                (boundary - unitf!(axis.of(&element.position)) - element.size_on(&axis)).into();
+            element.goto(new_pos);
+        }
+    }
+
+    /// Mirror the layout along every axis its flow is reversed on.
+    pub(crate) fn reverse_flow(&mut self) {
+        if self.reverse {
+            self.mirror(self.main_axis());
+        }
+
+        if self.wrap_reverse {
+            self.mirror(self.main_axis().cross());
+        }
+    }
+
+    /// Round the edges of every element to the nearest device pixel.
+    ///
+    /// Both edges are rounded (instead of the position and size separately), so
+    /// elements which shared an edge before snapping still share one afterwards.
+    ///
+    /// The rounding is done in the layout's own scalar type, which can't always hold
+    /// the scale factor exactly. Integer layouts round it to a whole number, which
+    /// leaves their (already whole) pixels as they are.
+    pub fn snap_to_pixels(&mut self) {
+        let scale = S::from_f64(self.units().scale_factor);
+        if scale <= S::ZERO {
+            return;
+        }
+
+        let snap = |position: SizeUnit<S>, size: SizeUnit<S>| -> (SizeUnit<S>, SizeUnit<S>) {
+            let start = (unitf!(position.clone()) * scale).round() / scale;
+            let end = (unitf!(position + size) * scale).round() / scale;
+            (start.into(), (end - start).into())
+        };
+
+        for element in self.inner.iter_mut() {
+            let (x, width) = snap(element.position.0.clone(), element.size.0.clone());
+            let (y, height) = snap(element.position.1.clone(), element.size.1.clone());
+
+            element.goto((x, y));
+            element.resize((width, height));
+        }
+    }
+
+    /// The unit context which elements in this layout are resolved against.
+    pub fn units(&self) -> UnitContext<S> {
+        let inherited = self.inherited.unwrap_or_default();
+        let font_size = match &self.properties.font_size {
+            // relative font sizes are relative to the inherited font size
+            Some(size) => unitf!(size.resolve(Direction::Y, inherited.font_size, &inherited)),
+            None => inherited.font_size,
+        };
+
+        match self.inherited {
+            Some(inherited) => UnitContext {
+                font_size,
+                ..inherited
+            },
+            // this is the root layout, so everything is relative to it
+            None => UnitContext {
+                font_size,
+                root_font_size: font_size,
+                viewport: (unitf!(self.size.0.clone()), unitf!(self.size.1.clone())),
+                dpi: self.dpi,
+                cell_size: self.cell_size,
+                cell_grid: self.cell_grid,
+                scale_factor: self.scale_factor,
+                pixel_snap: self.pixel_snap,
+            },
+        }
+    }
+
+    /// The spacing between each element along the given direction, in pixels.
+    pub(crate) fn offset(&self, direction: Direction) -> S {
+        let parent = direction.of(&self.content_box());
+
+        let gap = match direction {
+            Direction::X => &self.properties.column_gap,
+            Direction::Y => &self.properties.row_gap,
+        };
+
+        let units = self.units();
+        unitf!(units.resolve_snapped(
+            gap.as_ref().unwrap_or(&self.properties.offset),
+            direction,
+            parent
+        ))
+    }
+
+    /// Resolve the real size/position of every element against the layout's size.
+    pub(crate) fn resolve_units(&mut self) {
+        // relative units inside of the layout are relative to the space inside its padding
+        let (width, height) = self.content_box();
+        let units = self.units();
+
+        for element in self.inner.iter_mut() {
+            let inherited = Some(units);
+            let inherited_changed = element.sublayout.inherited != inherited;
+            element.sublayout.inherited = inherited;
+
+            element.goto((
+                units.resolve_snapped(&element.real_position.0, Direction::X, width),
+                units.resolve_snapped(&element.real_position.1, Direction::Y, height),
+            ));
+
+            let mut new_size = (
+                units.resolve_snapped(&element.real_size.0, Direction::X, width),
+                units.resolve_snapped(&element.real_size.1, Direction::Y, height),
+            );
+
+            // the size is the same, but units within the sublayout may not be
+            let mut relayout = inherited_changed;
+
+            let auto = (element.real_size.0.is_auto(), element.real_size.1.is_auto());
+            if auto.0 || auto.1 {
+                // lay the contents out in all of the space available to them first,
+                // then shrink the element down so it just fits them
+                element.sublayout.size = (
+                    if auto.0 {
+                        width.into()
+                    } else {
+                        new_size.0.clone()
+                    },
+                    if auto.1 {
+                        height.into()
+                    } else {
+                        new_size.1.clone()
+                    },
+                );
+                element.sublayout.recalculate();
+
+                // content is already snapped, since the sublayout inherits cell-grid mode
+                let content = element.sublayout.content_size();
+                if auto.0 {
+                    new_size.0 = content.0.into();
+                }
+
+                if auto.1 {
+                    new_size.1 = content.1.into();
+                }
+
+                relayout = true;
+            }
+
+            if new_size != element.size {
+                element.resize(new_size);
+            } else if relayout {
+                element.sublayout.size = element.size.clone();
+                element.sublayout.recalculate();
+            }
+        }
+    }
+
+    /// Share the space left over along the cross axis between rows, following
+    /// [`AlignContent`]. Rows which are stretched have their size in `rows` grown.
+    fn align_content(&mut self, rows: &mut [(Vec<usize>, S)], cross: Direction, boundary: S) {
+        let Some(last) = rows.last() else {
+            return;
+        };
+
+        let end = unitf!(cross.of(&self.inner[last.0[0]].position)) + last.1;
+        let free = boundary - end;
+
+        if free <= S::ZERO {
+            return;
+        }
+
+        let count = S::from_f64(rows.len() as f64);
+        let two = S::from_f64(2.0);
+        let (lead, between, grow) = match self.properties.align_content {
+            AlignContent::Start => return,
+            AlignContent::End => (free, S::ZERO, S::ZERO),
+            AlignContent::Center => (free / two, S::ZERO, S::ZERO),
+            AlignContent::SpaceBetween if rows.len() > 1 => {
+                (S::ZERO, free / (count - S::ONE), S::ZERO)
+            }
+            AlignContent::SpaceBetween => (S::ZERO, S::ZERO, S::ZERO),
+            AlignContent::SpaceAround => (free / count / two, free / count, S::ZERO),
+            AlignContent::Stretch => (S::ZERO, free / count, free / count),
+        };
+
+        let units = self.units();
+        let mut shift = lead;
+
+        for (row, row_size) in rows.iter_mut() {
+            let snapped = units.snap(shift, cross);
+            for &i in row.iter() {
+                let element = &mut self.inner[i];
+                let mut new_pos = element.position.clone();
+                *cross.of_mut(&mut new_pos) += snapped;
+                element.goto(new_pos);
+            }
+
+            *row_size += grow;
+            shift += between;
+        }
+    }
+
+    /// Place every relatively positioned element after the one before it along the
+    /// main axis, moving onto a new row whenever one doesn't fit and the layout wraps.
+    /// Each element is moved along by `basis` as it is placed.
+    pub(crate) fn place_in_rows(&mut self, basis: Vector2<S>) {
+        let mut previous_element: Option<&mut Element<S>> = None;
+        // where the current row starts along the cross axis, and how far it reaches
+        let mut row_start = S::ZERO;
+        let mut tallest_of_row = S::ZERO;
+        // every element after the first row has been wrapped
+        let mut wrapped = false;
+        let mut row = 0;
+
+        // rows run along the main axis, and are stacked along the cross axis
+        let main = self.main_axis();
+        let cross = main.cross();
+        let offset_main = self.offset(main);
+        let offset_cross = self.offset(cross);
+        let boundary = main.of(&self.content_box());
+
+        let mut cloned = self.inner.clone();
+
+        for element in cloned.iter_mut() {
+            if element.attrs.style == PositionStyle::Absolute {
+                // should not attempt to move absolute element
+                continue;
+            }
+
+            let pre = match previous_element {
+                Some(ref e) => e,
+                None => {
+                    // not really anything to adjust since this is the first element,
+                    // but it starts the first row
+                    row_start = unitf!(cross.of(&element.position));
+                    tallest_of_row = unitf!(cross.of(&element.size));
+                    element.row = 0;
+                    previous_element = Some(element);
+                    continue;
+                }
+            };
+
+            let mut new_pos = pre.position.clone();
+            *main.of_mut(&mut new_pos) =
+                main.of(&basis) + main.of(&pre.position) + main.of(&pre.size) + unit!(offset_main);
+            // we're going to stay on the same row (unless we overflow)
+            *cross.of_mut(&mut new_pos) = cross.of(&basis) + cross.of(&pre.position);
+
+            // the gap only goes between elements, so the last one in a row can touch the edge
+            if unitf!(main.of(&new_pos) + main.of(&element.size)) > boundary
+                && self.overflow == Overflow::Wrap
+            {
+                // do wrap, moving past the tallest element of the row so that we're
+                // under every element in it
+                row_start += tallest_of_row + offset_cross;
+                *main.of_mut(&mut new_pos) = S::ZERO.into();
+                *cross.of_mut(&mut new_pos) = row_start.into();
+                tallest_of_row = unitf!(cross.of(&element.size)); // this is the first element in the new row, so it is the tallest
+                wrapped = true;
+                row += 1;
+            }
+
+            if unitf!(cross.of(&element.size)) > tallest_of_row {
+                tallest_of_row = unitf!(cross.of(&element.size));
+            }
+
+            element.overflowed = wrapped;
+            element.row = row;
+            element.goto(new_pos);
+            previous_element = Some(element);
+        }
+
+        self.inner = cloned;
+        self.align_rows();
+    }
+
+    /// The indices of every relatively positioned element, split into the rows they
+    /// were placed in.
+    pub(crate) fn rows(&self) -> Vec<Vec<usize>> {
+        let mut rows: Vec<Vec<usize>> = Vec::new();
+
+        for (i, element) in self.inner.iter().enumerate() {
+            if element.attrs.style == PositionStyle::Absolute {
+                continue;
+            }
+
+            match rows.last_mut() {
+                Some(row) if self.inner[row[0]].row == element.row => row.push(i),
+                _ => rows.push(vec![i]),
+            }
+        }
+
+        rows
+    }
+
+    /// Align every element within its row, following [`AlignmentY`].
+    ///
+    /// Rows run along the main axis (the horizontal one, unless this is a vertical
+    /// flexible layout), so this has to be run straight after the elements are placed.
+    /// A flexible layout with a single row aligns its elements to its own size, instead
+    /// of the largest element's.
+    pub(crate) fn align_rows(&mut self) {
+        let cross = self.main_axis().cross();
+        let rows = self.rows();
+
+        let boundary = cross.of(&self.content_box());
+        let single_row = self.variant == LayoutType::Flexible && rows.len() == 1;
+        let units = self.units();
+
+        let mut rows: Vec<(Vec<usize>, S)> = rows
+            .into_iter()
+            .map(|row| {
+                let row_size = if single_row {
+                    boundary - unitf!(cross.of(&self.inner[row[0]].position))
+                } else {
+                    row.iter().fold(S::ZERO, |largest, &i| {
+                        largest.max(self.inner[i].size_on(&cross))
+                    })
+                };
+
+                (row, row_size)
+            })
+            .collect();
+
+        if !single_row {
+            self.align_content(&mut rows, cross, boundary);
+        }
+
+        for (row, row_size) in rows {
+            for i in row {
+                let element = &mut self.inner[i];
+                let alignment = element
+                    .attrs
+                    .align_self
+                    .as_ref()
+                    .unwrap_or(&self.properties.align_y);
+                let element_size = element.size_on(&cross);
+
+                let shift = match alignment {
+                    AlignmentY::Top => continue,
+                    AlignmentY::Center => (row_size - element_size) / S::from_f64(2.0),
+                    AlignmentY::Bottom => row_size - element_size,
+                    AlignmentY::Stretch => {
+                        let mut stretched = row_size;
+                        if let Some(max) = &element.attrs.max_size {
+                            stretched = stretched.min(unitf!(units.resolve_snapped(
+                                &cross.of(max),
+                                cross,
+                                boundary
+                            )));
+                        }
+
+                        let mut new_size = element.size.clone();
+                        *cross.of_mut(&mut new_size) = stretched.max(element_size).into();
+                        element.resize(new_size);
+                        continue;
+                    }
+                };
+
+                let mut new_pos = element.position.clone();
+                *cross.of_mut(&mut new_pos) += units.snap(shift, cross);
+                element.goto(new_pos);
+            }
+        }
+    }
+
+    /// The size of the area covered by every relatively positioned element in the layout,
+    /// plus the layout's padding.
+    pub fn content_size(&self) -> (S, S) {
+        let (start, end) = self.padding_edges();
+        let mut size: (S, S) = start;
+
+        for (i, element) in self.inner.iter().enumerate() {
+            if element.attrs.style == PositionStyle::Absolute || self.is_hidden(i) {
+                continue;
+            }
+
+            size.0 = size
+                .0
+                .max(unitf!(element.position.0.clone() + element.size.0.clone()));
+            size.1 = size
+                .1
+                .max(unitf!(element.position.1.clone() + element.size.1.clone()));
+        }
+
+        (size.0 + end.0, size.1 + end.1)
+    }
+
+    /// The element at the given index.
+    pub fn get(&self, idx: usize) -> Option<&Element<S>> {
+        self.inner.get(idx)
+    }
+
     /// Add an element to the layout and calculate its position/size.
     ///
     /// Also adjusts the size of every other element in the layout.
@@ -107,7 +878,7 @@ impl Layout {
     ///
     /// # Returns
     /// New element's index in the layout.
-    pub fn add(&mut self, element: Element) -> usize {
+    pub fn add(&mut self, element: Element<S>) -> usize {
         self.inner.push(element);
         self.recalculate();
         self.inner.len()
@@ -115,7 +886,7 @@ impl Layout {
 
     /// Remove an element from the layout and calculate the position/size
     /// of remaining elements.
-    pub fn remove(&mut self, idx: usize) -> () {
+    pub fn remove(&mut self, idx: usize) {
         self.inner.remove(idx);
         self.recalculate();
     }
@@ -125,12 +896,14 @@ impl Layout {
         let mut out: String = String::new();
 
         for (i, element) in self.inner.iter().enumerate() {
+            if self.is_hidden(i) {
+                continue;
+            }
+
+            let (left, top) = element.physical_position();
+            let (width, height) = element.physical_size();
             out.push_str(&format!(
-                "<div style=\"position: absolute; left: {}px; top: {}px; width: {}px; height: {}px\" id=\"{i}\"></div>",
-                element.position.0,
-                element.position.1,
-                element.size.0,
-                element.size.1
+                "<div style=\"position: absolute; left: {left}; top: {top}; width: {width}; height: {height}\" id=\"{i}\"></div>"
             ));
         }
 
@@ -0,0 +1,349 @@
+use std::{fmt::Display, str::FromStr};
+
+use super::{
+    scalar::Scalar,
+    unit::{Expression, SizeUnit},
+};
+
+/// Every unit suffix [`SizeUnit`] can be parsed from.
+const SUFFIXES: &str = "px, %, fr, em, rem, vw, vh, vmin, vmax, mm, pt, in, cell";
+
+/// Numbers which aren't written with digits, spelled the way floats display them.
+const NON_FINITE: [&str; 2] = ["inf", "NaN"];
+
+/// An error from parsing a [`SizeUnit`] from a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseUnitError {
+    /// The string was empty.
+    Empty,
+    /// Something other than what was expected was found at the given byte offset.
+    Expected {
+        expected: &'static str,
+        found: Option<char>,
+        position: usize,
+    },
+    /// A number couldn't be read.
+    InvalidNumber(String),
+    /// The suffix after a number isn't a known unit.
+    UnknownUnit(String),
+    /// The function isn't one of `calc`, `min`, `max` or `clamp`.
+    UnknownFunction(String),
+    /// A function was given the wrong number of arguments.
+    ArgumentCount {
+        function: String,
+        expected: &'static str,
+        found: usize,
+    },
+    /// A fraction or `auto` was used inside of an expression, where it can't be
+    /// calculated with.
+    NotCalculable(String),
+}
+
+impl Display for ParseUnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => f.write_str("cannot parse a unit from an empty string"),
+            Self::Expected {
+                expected,
+                found: Some(found),
+                position,
+            } => write!(f, "expected {expected} at {position}, found {found:?}"),
+            Self::Expected {
+                expected,
+                found: None,
+                position,
+            } => write!(
+                f,
+                "expected {expected} at {position}, found the end of the string"
+            ),
+            Self::InvalidNumber(number) => write!(f, "{number:?} is not a valid number"),
+            Self::UnknownUnit(unit) => {
+                write!(f, "unknown unit {unit:?} (expected one of {SUFFIXES})")
+            }
+            Self::UnknownFunction(function) => write!(
+                f,
+                "unknown function {function:?} (expected one of calc, min, max, clamp)"
+            ),
+            Self::ArgumentCount {
+                function,
+                expected,
+                found,
+            } => write!(f, "{function}() takes {expected} arguments, found {found}"),
+            Self::NotCalculable(unit) => write!(f, "{unit} cannot be used in an expression"),
+        }
+    }
+}
+
+impl std::error::Error for ParseUnitError {}
+
+/// Parses CSS-like units, such as `12px`, `50%`, `auto` or `calc(100% - 2em)`.
+///
+/// Numbers without a suffix are read as pixels. They may have an exponent (`1e3px`),
+/// and can be `inf` or `NaN`, so every unit can be read back from its [`Display`]
+/// output.
+impl<S: Scalar> FromStr for SizeUnit<S> {
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(ParseUnitError::Empty);
+        }
+
+        let mut parser = Parser {
+            input: s,
+            position: 0,
+        };
+        let unit = parser.value()?;
+
+        parser.skip_whitespace();
+        if parser.position < s.len() {
+            return Err(parser.expected("the end of the string"));
+        }
+
+        Ok(unit)
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    /// Consume characters for as long as `predicate` holds.
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &str {
+        let start = self.position;
+
+        while let Some(c) = self.peek().filter(|&c| predicate(c)) {
+            self.position += c.len_utf8();
+        }
+
+        &self.input[start..self.position]
+    }
+
+    fn expected(&self, expected: &'static str) -> ParseUnitError {
+        ParseUnitError::Expected {
+            expected,
+            found: self.peek(),
+            position: self.position,
+        }
+    }
+
+    fn eat(&mut self, c: char, expected: &'static str) -> Result<(), ParseUnitError> {
+        self.skip_whitespace();
+
+        if self.peek() != Some(c) {
+            return Err(self.expected(expected));
+        }
+
+        self.position += 1;
+        Ok(())
+    }
+
+    /// A single unit: a number with a suffix, `auto`, or a function.
+    fn value<S: Scalar>(&mut self) -> Result<SizeUnit<S>, ParseUnitError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some(c) if c.is_ascii_alphabetic() && self.non_finite().is_none() => {
+                let name = self.take_while(|c| c.is_ascii_alphanumeric()).to_string();
+
+                if name == "auto" {
+                    return Ok(SizeUnit::Auto);
+                }
+
+                self.eat('(', "\"(\"")?;
+                let arguments = self
+                    .arguments()?
+                    .into_iter()
+                    .map(operand)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let found = arguments.len();
+                let count = |expected: &'static str| ParseUnitError::ArgumentCount {
+                    function: name.clone(),
+                    expected,
+                    found,
+                };
+
+                match name.as_str() {
+                    "calc" if found == 1 => Ok(arguments.into_iter().next().unwrap()),
+                    "calc" => Err(count("1")),
+                    "min" | "max" if found < 2 => Err(count("at least 2")),
+                    "min" => Ok(fold(arguments, Expression::Min)),
+                    "max" => Ok(fold(arguments, Expression::Max)),
+                    "clamp" => match <[SizeUnit<S>; 3]>::try_from(arguments) {
+                        Ok([min, preferred, max]) => Ok(SizeUnit::Calc(Box::new(
+                            Expression::Clamp(min, preferred, max),
+                        ))),
+                        Err(_) => Err(count("3")),
+                    },
+                    _ => Err(ParseUnitError::UnknownFunction(name)),
+                }
+            }
+            Some('(') => {
+                self.position += 1;
+                let unit = self.sum()?;
+                self.eat(')', "\")\"")?;
+                Ok(unit)
+            }
+            _ => self.dimension(),
+        }
+    }
+
+    /// A number followed by an optional unit suffix.
+    fn dimension<S: Scalar>(&mut self) -> Result<SizeUnit<S>, ParseUnitError> {
+        let start = self.position;
+
+        if matches!(self.peek(), Some('-' | '+')) {
+            self.position += 1;
+        }
+
+        if let Some(number) = self.non_finite() {
+            self.position += number.len();
+        } else {
+            let digits = self.take_while(|c| c.is_ascii_digit() || c == '.');
+            if digits.is_empty() {
+                self.position = start;
+                return Err(self.expected("a number"));
+            }
+
+            self.exponent();
+        }
+
+        let number = &self.input[start..self.position];
+        let value = S::from_f64(
+            number
+                .parse::<f64>()
+                .map_err(|_| ParseUnitError::InvalidNumber(number.to_string()))?,
+        );
+
+        let suffix = self.take_while(|c| c.is_ascii_alphabetic() || c == '%');
+        Ok(match suffix {
+            "" | "px" => SizeUnit::Pixel(value),
+            "%" => SizeUnit::Percent(value),
+            "fr" => SizeUnit::Fraction(value),
+            "em" => SizeUnit::Em(value),
+            "rem" => SizeUnit::Rem(value),
+            "vw" => SizeUnit::Vw(value),
+            "vh" => SizeUnit::Vh(value),
+            "vmin" => SizeUnit::Vmin(value),
+            "vmax" => SizeUnit::Vmax(value),
+            "mm" => SizeUnit::Millimetre(value),
+            "pt" => SizeUnit::Point(value),
+            "in" => SizeUnit::Inch(value),
+            "cell" => SizeUnit::Cell(value),
+            _ => return Err(ParseUnitError::UnknownUnit(suffix.to_string())),
+        })
+    }
+
+    /// The number without digits (see [`NON_FINITE`]) which comes next, if any.
+    fn non_finite(&self) -> Option<&'static str> {
+        let rest = &self.input[self.position..];
+        NON_FINITE
+            .into_iter()
+            .find(|number| rest.starts_with(number))
+    }
+
+    /// The exponent of a number, as long as the `e` isn't the start of a suffix
+    /// (like `em`).
+    fn exponent(&mut self) {
+        let rest = &self.input.as_bytes()[self.position..];
+        let sign = usize::from(matches!(rest.get(1), Some(b'-' | b'+')));
+
+        if matches!(rest.first(), Some(b'e' | b'E'))
+            && rest.get(1 + sign).is_some_and(u8::is_ascii_digit)
+        {
+            self.position += 1 + sign;
+            self.take_while(|c| c.is_ascii_digit());
+        }
+    }
+
+    /// Comma separated sums, up to and including the closing bracket.
+    fn arguments<S: Scalar>(&mut self) -> Result<Vec<SizeUnit<S>>, ParseUnitError> {
+        let mut arguments = vec![self.sum()?];
+
+        loop {
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(',') => {
+                    self.position += 1;
+                    arguments.push(self.sum()?);
+                }
+                Some(')') => {
+                    self.position += 1;
+                    return Ok(arguments);
+                }
+                _ => return Err(self.expected("\",\" or \")\"")),
+            }
+        }
+    }
+
+    /// Products added or subtracted from each other.
+    fn sum<S: Scalar>(&mut self) -> Result<SizeUnit<S>, ParseUnitError> {
+        let mut unit = self.product()?;
+
+        loop {
+            self.skip_whitespace();
+
+            let operation = match self.peek() {
+                Some('+') => Expression::Add,
+                Some('-') => Expression::Sub,
+                _ => return Ok(unit),
+            };
+
+            self.position += 1;
+            unit = SizeUnit::Calc(Box::new(operation(
+                operand(unit)?,
+                operand(self.product()?)?,
+            )));
+        }
+    }
+
+    /// Values multiplied or divided by each other.
+    fn product<S: Scalar>(&mut self) -> Result<SizeUnit<S>, ParseUnitError> {
+        let mut unit = self.value()?;
+
+        loop {
+            self.skip_whitespace();
+
+            let operation = match self.peek() {
+                Some('*') => Expression::Mul,
+                Some('/') => Expression::Div,
+                _ => return Ok(unit),
+            };
+
+            self.position += 1;
+            unit = SizeUnit::Calc(Box::new(operation(operand(unit)?, operand(self.value()?)?)));
+        }
+    }
+}
+
+/// Combine every argument into nested expressions, from left to right.
+fn fold<S: Scalar>(
+    arguments: Vec<SizeUnit<S>>,
+    operation: fn(SizeUnit<S>, SizeUnit<S>) -> Expression<S>,
+) -> SizeUnit<S> {
+    arguments
+        .into_iter()
+        .reduce(|a, b| SizeUnit::Calc(Box::new(operation(a, b))))
+        .unwrap()
+}
+
+/// Check that a unit can be used inside of an expression.
+fn operand<S: Scalar>(unit: SizeUnit<S>) -> Result<SizeUnit<S>, ParseUnitError> {
+    if unit.is_calculable() {
+        Ok(unit)
+    } else {
+        Err(ParseUnitError::NotCalculable(unit.to_string()))
+    }
+}
@@ -0,0 +1,113 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// A number type which layouts can be calculated in.
+pub trait Scalar:
+    Copy
+    + Debug
+    + Display
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// The smallest difference between two values. Zero for float scalars, which
+    /// are treated as continuous.
+    const STEP: Self = Self::ZERO;
+
+    /// Convert from an `f64`, rounding if the scalar can't represent it exactly.
+    fn from_f64(value: f64) -> Self;
+    /// Convert into an `f64`.
+    fn to_f64(self) -> f64;
+    /// Round to the nearest whole number.
+    fn round(self) -> Self;
+    /// Round down to a whole number.
+    fn floor(self) -> Self;
+
+    /// Divide by `rhs`, or `None` if it is zero. Integer scalars panic when divided
+    /// by zero, and float scalars give an infinite (or NaN) result.
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs == Self::ZERO {
+            None
+        } else {
+            Some(self / rhs)
+        }
+    }
+
+    /// The absolute value of the number.
+    fn abs(self) -> Self {
+        if self < Self::ZERO { -self } else { self }
+    }
+
+    /// The smaller of two numbers.
+    fn min(self, other: Self) -> Self {
+        if other < self { other } else { self }
+    }
+
+    /// The larger of two numbers.
+    fn max(self, other: Self) -> Self {
+        if other > self { other } else { self }
+    }
+}
+
+macro_rules! float_scalar {
+    ($t:ty) => {
+        impl Scalar for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn round(self) -> Self {
+                <$t>::round(self)
+            }
+
+            fn floor(self) -> Self {
+                <$t>::floor(self)
+            }
+        }
+    };
+}
+
+float_scalar!(f32);
+float_scalar!(f64);
+
+impl Scalar for i32 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+    const STEP: Self = 1;
+
+    fn from_f64(value: f64) -> Self {
+        value.round() as i32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn round(self) -> Self {
+        self
+    }
+
+    fn floor(self) -> Self {
+        self
+    }
+}
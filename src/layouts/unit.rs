@@ -3,185 +3,623 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
+use super::{Direction, fixed::Fixed, scalar::Scalar};
+
+const MILLIMETRES_PER_INCH: f64 = 25.4;
+const POINTS_PER_INCH: f64 = 72.0;
+
 /// Sizing units.
-#[derive(Clone, Debug, PartialEq, PartialOrd, Copy)]
-pub enum SizeUnit {
-    Pixel(f64),
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub enum SizeUnit<S = f64> {
+    /// An absolute number of pixels.
+    Pixel(S),
+    /// A percentage (`0.0..=100.0`) of the containing layout's size along the same axis.
+    Percent(S),
+    /// A share (CSS `fr`) of the space left over in a flexible layout once every
+    /// other element has been sized. Resolves to nothing outside of flexible layouts.
+    Fraction(S),
+    /// A multiple of the font size of the nearest layout which sets one.
+    Em(S),
+    /// A multiple of the root layout's font size.
+    Rem(S),
+    /// A percentage of the root element's width.
+    Vw(S),
+    /// A percentage of the root element's height.
+    Vh(S),
+    /// A percentage of the root element's smallest side.
+    Vmin(S),
+    /// A percentage of the root element's largest side.
+    Vmax(S),
+    /// A physical length in millimetres, converted to pixels using the root layout's DPI.
+    Millimetre(S),
+    /// A physical length in points (1/72 of an inch).
+    Point(S),
+    /// A physical length in inches.
+    Inch(S),
+    /// A number of terminal cells, sized by the root layout's cell size.
+    Cell(S),
+    /// As big as the contents of the element's sublayout. Resolves to nothing
+    /// anywhere other than an element's size.
+    Auto,
+    /// An expression mixing other units, evaluated once the layout it is in is known.
+    /// Fractions and auto sizes can't be used inside of one (see
+    /// [`Self::is_calculable`]).
+    Calc(Box<Expression<S>>),
 }
 
-impl SizeUnit {
+impl<S: Scalar> SizeUnit<S> {
+    /// The absolute value of a resolved unit.
+    ///
+    /// # Panics
+    /// If the unit hasn't been resolved yet (see [`Self::resolve`]).
     pub fn abs(self) -> Self {
-        let a: f64 = self.into();
-        Self::Pixel(a.abs())
+        Self::Pixel(self.pixels().abs())
+    }
+
+    /// The number of pixels in a resolved unit.
+    ///
+    /// # Panics
+    /// If the unit hasn't been resolved yet (see [`Self::resolve`]).
+    pub fn pixels(self) -> S {
+        match self {
+            Self::Pixel(a) => a,
+            _ => panic!("cannot convert an unresolved unit ({self:?}) to pixels"),
+        }
+    }
+
+    /// If the unit can be converted to pixels without knowing anything about
+    /// the layout it is in.
+    pub fn is_resolved(&self) -> bool {
+        matches!(self, Self::Pixel(_))
+    }
+
+    /// If the unit can be used inside of an [`Expression`]. Fractions and auto sizes
+    /// can't, since they are only known once the rest of the layout has been sized.
+    pub fn is_calculable(&self) -> bool {
+        !matches!(self, Self::Fraction(_) | Self::Auto)
+    }
+
+    /// Wrap an expression in a unit, or give nothing if any unit in it isn't
+    /// calculable (see [`Self::is_calculable`]).
+    pub fn checked_calc(expression: Expression<S>) -> Option<Self> {
+        if !expression.operands().all(Self::is_calculable) {
+            return None;
+        }
+
+        Some(Self::Calc(Box::new(expression)))
+    }
+
+    /// Wrap an expression in a unit.
+    ///
+    /// # Panics
+    /// If any unit in the expression isn't calculable (see [`Self::is_calculable`]).
+    fn calc(expression: Expression<S>) -> Self {
+        if let Some(unit) = expression.operands().find(|unit| !unit.is_calculable()) {
+            panic!("{unit} cannot be used in an expression");
+        }
+
+        Self::Calc(Box::new(expression))
+    }
+
+    /// The smaller of two units (CSS `min()`).
+    ///
+    /// # Panics
+    /// If either unit isn't calculable (see [`Self::is_calculable`]).
+    pub fn min(self, other: Self) -> Self {
+        match (&self, &other) {
+            (Self::Pixel(a), Self::Pixel(b)) => Self::Pixel(a.min(*b)),
+            _ => Self::calc(Expression::Min(self, other)),
+        }
+    }
+
+    /// The larger of two units (CSS `max()`).
+    ///
+    /// # Panics
+    /// If either unit isn't calculable (see [`Self::is_calculable`]).
+    pub fn max(self, other: Self) -> Self {
+        match (&self, &other) {
+            (Self::Pixel(a), Self::Pixel(b)) => Self::Pixel(a.max(*b)),
+            _ => Self::calc(Expression::Max(self, other)),
+        }
+    }
+
+    /// Restrict the unit to the given bounds (CSS `clamp(min, self, max)`).
+    ///
+    /// # Panics
+    /// If any of the units isn't calculable (see [`Self::is_calculable`]).
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        match (&min, &self, &max) {
+            (Self::Pixel(a), Self::Pixel(b), Self::Pixel(c)) => Self::Pixel(b.min(*c).max(*a)),
+            _ => Self::calc(Expression::Clamp(min, self, max)),
+        }
+    }
+
+    /// The number held by units which aren't expressions.
+    fn value(&self) -> Option<S> {
+        match self {
+            Self::Pixel(a)
+            | Self::Percent(a)
+            | Self::Fraction(a)
+            | Self::Em(a)
+            | Self::Rem(a)
+            | Self::Vw(a)
+            | Self::Vh(a)
+            | Self::Vmin(a)
+            | Self::Vmax(a)
+            | Self::Millimetre(a)
+            | Self::Point(a)
+            | Self::Inch(a)
+            | Self::Cell(a) => Some(*a),
+            Self::Auto | Self::Calc(_) => None,
+        }
+    }
+
+    /// The same kind of unit, holding a different number. Expressions are left as-is.
+    fn with_value(&self, value: S) -> Self {
+        match self {
+            Self::Pixel(_) => Self::Pixel(value),
+            Self::Percent(_) => Self::Percent(value),
+            Self::Fraction(_) => Self::Fraction(value),
+            Self::Em(_) => Self::Em(value),
+            Self::Rem(_) => Self::Rem(value),
+            Self::Vw(_) => Self::Vw(value),
+            Self::Vh(_) => Self::Vh(value),
+            Self::Vmin(_) => Self::Vmin(value),
+            Self::Vmax(_) => Self::Vmax(value),
+            Self::Millimetre(_) => Self::Millimetre(value),
+            Self::Point(_) => Self::Point(value),
+            Self::Inch(_) => Self::Inch(value),
+            Self::Cell(_) => Self::Cell(value),
+            Self::Auto | Self::Calc(_) => self.clone(),
+        }
+    }
+
+    /// The length of physical units in inches.
+    pub fn inches(&self) -> Option<f64> {
+        match self {
+            Self::Millimetre(a) => Some(a.to_f64() / MILLIMETRES_PER_INCH),
+            Self::Point(a) => Some(a.to_f64() / POINTS_PER_INCH),
+            Self::Inch(a) => Some(a.to_f64()),
+            _ => None,
+        }
+    }
+
+    /// Convert a number of pixels into the same physical unit as this one. Units
+    /// which aren't physical lengths are given back in pixels.
+    pub fn convert_pixels(&self, pixels: S, context: &UnitContext<S>) -> Self {
+        let inches = pixels.to_f64() / context.dpi;
+
+        match self {
+            Self::Millimetre(_) => Self::Millimetre(S::from_f64(inches * MILLIMETRES_PER_INCH)),
+            Self::Point(_) => Self::Point(S::from_f64(inches * POINTS_PER_INCH)),
+            Self::Inch(_) => Self::Inch(S::from_f64(inches)),
+            _ => Self::Pixel(pixels),
+        }
+    }
+
+    /// If the unit is [`SizeUnit::Auto`].
+    pub fn is_auto(&self) -> bool {
+        matches!(self, Self::Auto)
+    }
+
+    /// If the unit is a [`SizeUnit::Fraction`].
+    pub fn is_fraction(&self) -> bool {
+        matches!(self, Self::Fraction(_))
+    }
+
+    /// Convert the unit into pixels.
+    ///
+    /// # Arguments
+    /// * `direction` - the axis the unit is measured along
+    /// * `parent` - the size of the containing layout along the same axis
+    /// * `context` - the values inherited by the containing layout
+    pub fn resolve(&self, direction: Direction, parent: S, context: &UnitContext<S>) -> Self {
+        let hundred = S::from_f64(100.0);
+        let viewport = context.viewport;
+
+        match self {
+            Self::Pixel(_) => self.clone(),
+            Self::Percent(p) => Self::Pixel(parent * *p / hundred),
+            Self::Em(e) => Self::Pixel(context.font_size * *e),
+            Self::Rem(e) => Self::Pixel(context.root_font_size * *e),
+            Self::Vw(v) => Self::Pixel(viewport.0 * *v / hundred),
+            Self::Vh(v) => Self::Pixel(viewport.1 * *v / hundred),
+            Self::Vmin(v) => Self::Pixel(viewport.0.min(viewport.1) * *v / hundred),
+            Self::Vmax(v) => Self::Pixel(viewport.0.max(viewport.1) * *v / hundred),
+            Self::Millimetre(_) | Self::Point(_) | Self::Inch(_) => {
+                Self::Pixel(S::from_f64(self.inches().unwrap() * context.dpi))
+            }
+            Self::Cell(c) => Self::Pixel(c.round() * context.cell(direction)),
+            // fractions are handed out by the flexible layout after everything else is sized
+            Self::Fraction(_) => Self::Pixel(S::ZERO),
+            // auto sizes are measured by the layout the element is in
+            Self::Auto => Self::Pixel(S::ZERO),
+            Self::Calc(expression) => Self::Pixel(expression.resolve(direction, parent, context)),
+        }
     }
 }
 
-impl Into<f64> for SizeUnit {
-    fn into(self) -> f64 {
+/// An operation on [`SizeUnit`]s which can't be simplified until they are resolved.
+///
+/// Fractions and auto sizes inside of an expression resolve to nothing, so the
+/// constructors on [`SizeUnit`] (and its parser) refuse to build them.
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub enum Expression<S = f64> {
+    Add(SizeUnit<S>, SizeUnit<S>),
+    Sub(SizeUnit<S>, SizeUnit<S>),
+    Mul(SizeUnit<S>, SizeUnit<S>),
+    Div(SizeUnit<S>, SizeUnit<S>),
+    Min(SizeUnit<S>, SizeUnit<S>),
+    Max(SizeUnit<S>, SizeUnit<S>),
+    /// `(min, preferred, max)`
+    Clamp(SizeUnit<S>, SizeUnit<S>, SizeUnit<S>),
+}
+
+impl<S: Scalar> Expression<S> {
+    /// Every unit the expression is made of.
+    pub fn operands(&self) -> impl Iterator<Item = &SizeUnit<S>> {
+        let (a, b, c) = match self {
+            Self::Add(a, b)
+            | Self::Sub(a, b)
+            | Self::Mul(a, b)
+            | Self::Div(a, b)
+            | Self::Min(a, b)
+            | Self::Max(a, b) => (a, b, None),
+            Self::Clamp(a, b, c) => (a, b, Some(c)),
+        };
+
+        [a, b].into_iter().chain(c)
+    }
+
+    /// Evaluate the expression in pixels.
+    ///
+    /// # Arguments
+    /// * `direction` - the axis the expression is measured along
+    /// * `parent` - the size of the containing layout along the same axis
+    /// * `context` - the values inherited by the containing layout
+    pub fn resolve(&self, direction: Direction, parent: S, context: &UnitContext<S>) -> S {
+        let r = |unit: &SizeUnit<S>| -> S { unit.resolve(direction, parent, context).pixels() };
+
         match self {
-            Self::Pixel(f) => f,
+            Self::Add(a, b) => r(a) + r(b),
+            Self::Sub(a, b) => r(a) - r(b),
+            Self::Mul(a, b) => r(a) * r(b),
+            // like every other unit that can't be resolved, dividing by zero gives nothing
+            Self::Div(a, b) => r(a).checked_div(r(b)).unwrap_or(S::ZERO),
+            Self::Min(a, b) => r(a).min(r(b)),
+            Self::Max(a, b) => r(a).max(r(b)),
+            Self::Clamp(min, preferred, max) => r(preferred).min(r(max)).max(r(min)),
         }
     }
 }
 
-impl From<f64> for SizeUnit {
-    fn from(value: f64) -> Self {
+impl<S: Scalar> Display for Expression<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Add(a, b) => write!(f, "calc({a} + {b})"),
+            Self::Sub(a, b) => write!(f, "calc({a} - {b})"),
+            Self::Mul(a, b) => write!(f, "calc({a} * {b})"),
+            Self::Div(a, b) => write!(f, "calc({a} / {b})"),
+            Self::Min(a, b) => write!(f, "min({a}, {b})"),
+            Self::Max(a, b) => write!(f, "max({a}, {b})"),
+            Self::Clamp(min, preferred, max) => write!(f, "clamp({min}, {preferred}, {max})"),
+        }
+    }
+}
+
+/// The values inherited from parent layouts which relative units are resolved against.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitContext<S = f64> {
+    /// The font size of the nearest layout which sets one.
+    pub font_size: S,
+    /// The font size of the root layout.
+    pub root_font_size: S,
+    /// The size of the root element (the one which started the layout).
+    pub viewport: (S, S),
+    /// The number of pixels in an inch, used to resolve physical units.
+    pub dpi: f64,
+    /// The size of a single terminal cell.
+    pub cell_size: (S, S),
+    /// If sizes and positions are snapped to whole cells.
+    pub cell_grid: bool,
+    /// The number of device pixels in each pixel.
+    pub scale_factor: f64,
+    /// If element edges are rounded to whole device pixels.
+    pub pixel_snap: bool,
+}
+
+impl<S: Scalar> UnitContext<S> {
+    /// The size of a single cell along the given direction.
+    pub fn cell(&self, direction: Direction) -> S {
+        match direction {
+            Direction::X => self.cell_size.0,
+            Direction::Y => self.cell_size.1,
+        }
+    }
+
+    /// Resolve a unit into pixels, rounded to the nearest whole cell in cell-grid mode.
+    pub fn resolve_snapped(
+        &self,
+        unit: &SizeUnit<S>,
+        direction: Direction,
+        parent: S,
+    ) -> SizeUnit<S> {
+        let pixels = unit.resolve(direction, parent, self).pixels();
+        SizeUnit::Pixel(self.snap(pixels, direction))
+    }
+
+    /// Round a number of pixels to the nearest whole cell in cell-grid mode.
+    pub fn snap(&self, value: S, direction: Direction) -> S {
+        if !self.cell_grid {
+            return value;
+        }
+
+        let cell = self.cell(direction);
+        match value.checked_div(cell) {
+            Some(cells) => cells.round() * cell,
+            // there's no grid to snap to
+            None => value,
+        }
+    }
+}
+
+impl<S: Scalar> Default for UnitContext<S> {
+    fn default() -> Self {
+        Self {
+            font_size: S::from_f64(16.0),
+            root_font_size: S::from_f64(16.0),
+            viewport: (S::ZERO, S::ZERO),
+            dpi: 96.0,
+            cell_size: (S::ONE, S::ONE),
+            cell_grid: false,
+            scale_factor: 1.0,
+            pixel_snap: false,
+        }
+    }
+}
+
+macro_rules! scalar_from_unit {
+    ($t:ty) => {
+        /// Gives back the unit if it hasn't been resolved to pixels yet (see
+        /// [`SizeUnit::resolve`]).
+        impl TryFrom<SizeUnit<$t>> for $t {
+            type Error = SizeUnit<$t>;
+            fn try_from(value: SizeUnit<$t>) -> Result<Self, Self::Error> {
+                match value {
+                    SizeUnit::Pixel(a) => Ok(a),
+                    _ => Err(value),
+                }
+            }
+        }
+    };
+}
+
+scalar_from_unit!(f32);
+scalar_from_unit!(f64);
+scalar_from_unit!(i32);
+scalar_from_unit!(Fixed);
+
+impl<S: Scalar> From<S> for SizeUnit<S> {
+    fn from(value: S) -> Self {
         Self::Pixel(value)
     }
 }
 
-impl Add for SizeUnit {
+impl<S: Scalar> Add for SizeUnit<S> {
     type Output = Self;
+    /// # Panics
+    /// If the units can't be combined right away and either of them isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
     fn add(self, rhs: Self) -> Self::Output {
-        let a: f64 = self.into();
-        let b: f64 = rhs.into();
-        Self::Pixel(a + b)
+        match (self.value(), rhs.value()) {
+            // units of the same kind can be added right away
+            (Some(a), Some(b)) if std::mem::discriminant(&self) == std::mem::discriminant(&rhs) => {
+                self.with_value(a + b)
+            }
+            _ => Self::calc(Expression::Add(self, rhs)),
+        }
     }
 }
 
-impl AddAssign for SizeUnit {
+impl<S: Scalar> AddAssign for SizeUnit<S> {
+    /// # Panics
+    /// If the units can't be combined right away and either of them isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
     fn add_assign(&mut self, rhs: Self) {
-        let a: f64 = self.clone().into();
-        let b: f64 = rhs.into();
-        *self = Self::Pixel(a + b)
+        *self = self.clone() + rhs
     }
 }
 
-impl Div for SizeUnit {
+impl<S: Scalar> Div for SizeUnit<S> {
     type Output = Self;
+    /// # Panics
+    /// If the units can't be combined right away and either of them isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
     fn div(self, rhs: Self) -> Self::Output {
-        let a: f64 = self.into();
-        let b: f64 = rhs.into();
-        Self::Pixel(a / b)
+        match (self.value(), &rhs) {
+            // dividing by a plain number keeps the unit
+            (Some(a), Self::Pixel(b)) => self.with_value(a.checked_div(*b).unwrap_or(S::ZERO)),
+            _ => Self::calc(Expression::Div(self, rhs)),
+        }
     }
 }
 
-impl DivAssign for SizeUnit {
+impl<S: Scalar> DivAssign for SizeUnit<S> {
+    /// # Panics
+    /// If the units can't be combined right away and either of them isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
     fn div_assign(&mut self, rhs: Self) {
-        let a: f64 = self.clone().into();
-        let b: f64 = rhs.into();
-        *self = Self::Pixel(a / b)
+        *self = self.clone() / rhs
     }
 }
 
-impl Sub for SizeUnit {
+impl<S: Scalar> Sub for SizeUnit<S> {
     type Output = Self;
+    /// # Panics
+    /// If the units can't be combined right away and either of them isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
     fn sub(self, rhs: Self) -> Self::Output {
-        let a: f64 = self.into();
-        let b: f64 = rhs.into();
-        Self::Pixel(a - b)
+        match (self.value(), rhs.value()) {
+            (Some(a), Some(b)) if std::mem::discriminant(&self) == std::mem::discriminant(&rhs) => {
+                self.with_value(a - b)
+            }
+            _ => Self::calc(Expression::Sub(self, rhs)),
+        }
     }
 }
 
-impl SubAssign for SizeUnit {
+impl<S: Scalar> SubAssign for SizeUnit<S> {
+    /// # Panics
+    /// If the units can't be combined right away and either of them isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
     fn sub_assign(&mut self, rhs: Self) {
-        let a: f64 = self.clone().into();
-        let b: f64 = rhs.into();
-        *self = Self::Pixel(a - b)
+        *self = self.clone() - rhs
     }
 }
 
-impl Mul for SizeUnit {
+impl<S: Scalar> Mul for SizeUnit<S> {
     type Output = Self;
+    /// # Panics
+    /// If the units can't be combined right away and either of them isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
     fn mul(self, rhs: Self) -> Self::Output {
-        let a: f64 = self.into();
-        let b: f64 = rhs.into();
-        Self::Pixel(a * b)
+        match (self.value(), rhs.value()) {
+            // multiplying by a plain number keeps the unit
+            (Some(a), Some(b)) if matches!(rhs, Self::Pixel(_)) => self.with_value(a * b),
+            (Some(a), Some(b)) if matches!(self, Self::Pixel(_)) => rhs.with_value(a * b),
+            _ => Self::calc(Expression::Mul(self, rhs)),
+        }
     }
 }
 
-impl MulAssign for SizeUnit {
+impl<S: Scalar> MulAssign for SizeUnit<S> {
+    /// # Panics
+    /// If the units can't be combined right away and either of them isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
     fn mul_assign(&mut self, rhs: Self) {
-        let a: f64 = self.clone().into();
-        let b: f64 = rhs.into();
-        *self = Self::Pixel(a * b)
+        *self = self.clone() * rhs
     }
 }
 
-impl Add<f64> for SizeUnit {
-    type Output = f64;
-    fn add(self, rhs: f64) -> Self::Output {
-        let a: f64 = self.into();
-        a + rhs
+/// Combines the unit with a number of pixels, building an expression (like
+/// `calc(50% + 10px)`) if it can't be done right away.
+impl<S: Scalar> Add<S> for SizeUnit<S> {
+    type Output = Self;
+    /// # Panics
+    /// If the unit can't be combined with pixels right away and isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
+    fn add(self, rhs: S) -> Self::Output {
+        self + Self::Pixel(rhs)
     }
 }
 
-impl AddAssign<f64> for SizeUnit {
-    fn add_assign(&mut self, rhs: f64) {
-        let a: f64 = self.clone().into();
-        *self = Self::Pixel(a + rhs)
+impl<S: Scalar> AddAssign<S> for SizeUnit<S> {
+    /// # Panics
+    /// If the unit can't be combined with pixels right away and isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
+    fn add_assign(&mut self, rhs: S) {
+        *self = self.clone() + Self::Pixel(rhs)
     }
 }
 
-impl Div<f64> for SizeUnit {
-    type Output = f64;
-    fn div(self, rhs: f64) -> Self::Output {
-        let a: f64 = self.into();
-        a / rhs
+impl<S: Scalar> Div<S> for SizeUnit<S> {
+    type Output = Self;
+    /// # Panics
+    /// If the unit can't be combined with pixels right away and isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
+    fn div(self, rhs: S) -> Self::Output {
+        self / Self::Pixel(rhs)
     }
 }
 
-impl DivAssign<f64> for SizeUnit {
-    fn div_assign(&mut self, rhs: f64) {
-        let a: f64 = self.clone().into();
-        *self = Self::Pixel(a / rhs)
+impl<S: Scalar> DivAssign<S> for SizeUnit<S> {
+    /// # Panics
+    /// If the unit can't be combined with pixels right away and isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
+    fn div_assign(&mut self, rhs: S) {
+        *self = self.clone() / Self::Pixel(rhs)
     }
 }
 
-impl Sub<f64> for SizeUnit {
-    type Output = f64;
-    fn sub(self, rhs: f64) -> Self::Output {
-        let a: f64 = self.into();
-        a - rhs
+impl<S: Scalar> Sub<S> for SizeUnit<S> {
+    type Output = Self;
+    /// # Panics
+    /// If the unit can't be combined with pixels right away and isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
+    fn sub(self, rhs: S) -> Self::Output {
+        self - Self::Pixel(rhs)
     }
 }
 
-impl SubAssign<f64> for SizeUnit {
-    fn sub_assign(&mut self, rhs: f64) {
-        let a: f64 = self.clone().into();
-        *self = Self::Pixel(a - rhs)
+impl<S: Scalar> SubAssign<S> for SizeUnit<S> {
+    /// # Panics
+    /// If the unit can't be combined with pixels right away and isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
+    fn sub_assign(&mut self, rhs: S) {
+        *self = self.clone() - Self::Pixel(rhs)
     }
 }
 
-impl Mul<f64> for SizeUnit {
-    type Output = f64;
-    fn mul(self, rhs: f64) -> Self::Output {
-        let a: f64 = self.into();
-        a * rhs
+impl<S: Scalar> Mul<S> for SizeUnit<S> {
+    type Output = Self;
+    /// # Panics
+    /// If the unit can't be combined with pixels right away and isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
+    fn mul(self, rhs: S) -> Self::Output {
+        self * Self::Pixel(rhs)
     }
 }
 
-impl MulAssign<f64> for SizeUnit {
-    fn mul_assign(&mut self, rhs: f64) {
-        let a: f64 = self.clone().into();
-        *self = Self::Pixel(a * rhs)
+impl<S: Scalar> MulAssign<S> for SizeUnit<S> {
+    /// # Panics
+    /// If the unit can't be combined with pixels right away and isn't calculable
+    /// (see [`SizeUnit::is_calculable`] and [`SizeUnit::checked_calc`]).
+    fn mul_assign(&mut self, rhs: S) {
+        *self = self.clone() * Self::Pixel(rhs)
     }
 }
 
-impl Display for SizeUnit {
+impl<S: Scalar> Display for SizeUnit<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let a: f64 = self.clone().into();
-        f.write_str(&a.to_string())
+        match self {
+            Self::Pixel(a) => f.write_str(&format!("{a}px")),
+            Self::Percent(a) => f.write_str(&format!("{a}%")),
+            Self::Fraction(a) => f.write_str(&format!("{a}fr")),
+            Self::Em(a) => f.write_str(&format!("{a}em")),
+            Self::Rem(a) => f.write_str(&format!("{a}rem")),
+            Self::Vw(a) => f.write_str(&format!("{a}vw")),
+            Self::Vh(a) => f.write_str(&format!("{a}vh")),
+            Self::Vmin(a) => f.write_str(&format!("{a}vmin")),
+            Self::Vmax(a) => f.write_str(&format!("{a}vmax")),
+            Self::Millimetre(a) => f.write_str(&format!("{a}mm")),
+            Self::Point(a) => f.write_str(&format!("{a}pt")),
+            Self::Inch(a) => f.write_str(&format!("{a}in")),
+            Self::Cell(a) => f.write_str(&format!("{a}cell")),
+            Self::Auto => f.write_str("auto"),
+            Self::Calc(a) => a.fmt(f),
+        }
     }
 }
 
-impl PartialEq<f64> for SizeUnit {
-    fn eq(&self, other: &f64) -> bool {
-        let a: f64 = self.clone().into();
-        &a == other
+impl<S: Scalar> PartialEq<S> for SizeUnit<S> {
+    fn eq(&self, other: &S) -> bool {
+        match self {
+            Self::Pixel(a) => a == other,
+            _ => false,
+        }
     }
 }
 
 #[macro_export]
 macro_rules! unitf {
     ($x:expr) => {
-        <SizeUnit as Into<f64>>::into($x)
+        SizeUnit::pixels($x)
     };
 }
 
 #[macro_export]
 macro_rules! unit {
     ($x:expr) => {
-        <SizeUnit as From<f64>>::from($x)
+        SizeUnit::Pixel($x)
     };
 }
@@ -0,0 +1,75 @@
+mod common;
+
+use clemen::layouts::{AlignContent, AlignmentY, Direction, LayoutType, element::Element};
+use common::{element, root, spans};
+
+/// A 100x180 block layout with three 60x40 rows, leaving 60 pixels to share out.
+fn rows(align_content: AlignContent, align_y: AlignmentY) -> Element {
+    let mut root = root((100.0, 180.0), LayoutType::Block);
+    root.sublayout.properties.align_content = align_content;
+    root.sublayout.properties.align_y = align_y;
+    for _ in 0..3 {
+        root.sublayout.add(element(60.0, 40.0));
+    }
+
+    root
+}
+
+#[test]
+fn rows_share_the_space_left_over() {
+    let cases = [
+        (AlignContent::Start, [0.0, 40.0, 80.0]),
+        (AlignContent::Center, [30.0, 70.0, 110.0]),
+        (AlignContent::End, [60.0, 100.0, 140.0]),
+        (AlignContent::SpaceBetween, [0.0, 70.0, 140.0]),
+        (AlignContent::SpaceAround, [10.0, 70.0, 130.0]),
+        (AlignContent::Stretch, [0.0, 60.0, 120.0]),
+    ];
+
+    for (align_content, tops) in cases {
+        let root = rows(align_content.clone(), AlignmentY::Top);
+        let expected: Vec<(f64, f64)> = tops.iter().map(|&top| (top, 40.0)).collect();
+
+        assert_eq!(spans(&root, Direction::Y), expected, "{align_content:?}");
+    }
+}
+
+#[test]
+fn stretched_rows_stretch_their_elements() {
+    let root = rows(AlignContent::Stretch, AlignmentY::Stretch);
+
+    assert_eq!(
+        spans(&root, Direction::Y),
+        [(0.0, 60.0), (60.0, 60.0), (120.0, 60.0)]
+    );
+}
+
+#[test]
+fn elements_are_aligned_within_stretched_rows() {
+    let root = rows(AlignContent::Stretch, AlignmentY::Bottom);
+
+    assert_eq!(
+        spans(&root, Direction::Y),
+        [(20.0, 40.0), (80.0, 40.0), (140.0, 40.0)]
+    );
+}
+
+#[test]
+fn rows_never_overlap_a_tall_first_element() {
+    let cases = [
+        (AlignContent::Center, [65.0, 65.0, 115.0]),
+        (AlignContent::End, [130.0, 130.0, 180.0]),
+        (AlignContent::Stretch, [0.0, 0.0, 115.0]),
+    ];
+
+    for (align_content, tops) in cases {
+        let mut root = root((100.0, 200.0), LayoutType::Block);
+        root.sublayout.properties.align_content = align_content.clone();
+        root.sublayout.add(element(40.0, 50.0));
+        root.sublayout.add(element(40.0, 20.0));
+        root.sublayout.add(element(40.0, 20.0));
+
+        let expected: Vec<(f64, f64)> = tops.into_iter().zip([50.0, 20.0, 20.0]).collect();
+        assert_eq!(spans(&root, Direction::Y), expected, "{align_content:?}");
+    }
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use clemen::layouts::{
+    AlignmentX, Direction, LayoutType,
+    element::{Element, PositionStyle},
+};
+use common::{element, positions, root};
+
+/// A 500x500 flexible layout, which doesn't grow its elements.
+fn aligned(align_x: AlignmentX) -> Element {
+    let mut root = root((500.0, 500.0), LayoutType::Flexible);
+    root.sublayout.properties.align_x = align_x;
+    root.sublayout.properties.flex_grow = false;
+
+    root
+}
+
+#[test]
+fn even_groups_are_centered() {
+    let mut root = aligned(AlignmentX::Center);
+    root.sublayout.properties.offset = 10.0.into();
+    root.sublayout.add(element(100.0, 100.0));
+    root.sublayout.add(element(100.0, 100.0));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(positions(&root), [(145.0, 0.0), (255.0, 0.0)]);
+}
+
+#[test]
+fn odd_groups_are_centered() {
+    let mut root = aligned(AlignmentX::Center);
+    for _ in 0..3 {
+        root.sublayout.add(element(100.0, 100.0));
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(positions(&root), [(100.0, 0.0), (200.0, 0.0), (300.0, 0.0)]);
+}
+
+#[test]
+fn groups_are_aligned_to_the_end() {
+    let mut root = aligned(AlignmentX::End);
+    root.sublayout.add(element(100.0, 100.0));
+    root.sublayout.add(element(100.0, 100.0));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(positions(&root), [(300.0, 0.0), (400.0, 0.0)]);
+}
+
+#[test]
+fn groups_are_aligned_along_either_direction() {
+    let mut root = aligned(AlignmentX::End);
+    root.sublayout.direction = Direction::Y;
+    root.sublayout.add(element(100.0, 100.0));
+    root.sublayout.add(element(100.0, 100.0));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(positions(&root), [(0.0, 300.0), (0.0, 400.0)]);
+}
+
+#[test]
+fn absolute_elements_stay_in_place() {
+    let mut root = aligned(AlignmentX::Center);
+    root.sublayout.add(element(100.0, 100.0));
+
+    let mut absolute = Element::new(
+        (10.0.into(), 10.0.into()),
+        (5.0.into(), 5.0.into()),
+        LayoutType::Block,
+    );
+    absolute.attrs.style = PositionStyle::Absolute;
+    root.sublayout.add(absolute);
+    root.sublayout.resize_flexible();
+
+    assert_eq!(positions(&root), [(200.0, 0.0), (5.0, 5.0)]);
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use clemen::layouts::{AlignmentY, Direction, LayoutType};
+use common::{element, root, spans};
+
+#[test]
+fn block_rows_align_to_their_tallest_element() {
+    let cases = [
+        (AlignmentY::Top, [(0.0, 20.0), (0.0, 40.0), (0.0, 60.0)]),
+        (
+            AlignmentY::Center,
+            [(20.0, 20.0), (10.0, 40.0), (0.0, 60.0)],
+        ),
+        (
+            AlignmentY::Bottom,
+            [(40.0, 20.0), (20.0, 40.0), (0.0, 60.0)],
+        ),
+        (AlignmentY::Stretch, [(0.0, 60.0), (0.0, 60.0), (0.0, 60.0)]),
+    ];
+
+    for (align_y, expected) in cases {
+        let mut root = root((300.0, 100.0), LayoutType::Block);
+        root.sublayout.properties.align_y = align_y.clone();
+        for height in [20.0, 40.0, 60.0] {
+            root.sublayout.add(element(50.0, height));
+        }
+
+        assert_eq!(spans(&root, Direction::Y), expected, "{align_y:?}");
+    }
+}
+
+#[test]
+fn single_rows_align_to_the_layout() {
+    let mut root = root((300.0, 100.0), LayoutType::Flexible);
+    root.sublayout.properties.align_y = AlignmentY::Center;
+    root.sublayout.properties.flex_grow = false;
+    root.sublayout.add(element(50.0, 20.0));
+    root.sublayout.add(element(50.0, 40.0));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(spans(&root, Direction::Y), [(40.0, 20.0), (30.0, 40.0)]);
+}
+
+#[test]
+fn align_self_overrides_the_layout() {
+    let mut root = root((300.0, 100.0), LayoutType::Flexible);
+    root.sublayout.properties.flex_grow = false;
+    for align_self in [None, Some(AlignmentY::Bottom), Some(AlignmentY::Stretch)] {
+        let mut element = element(50.0, 20.0);
+        element.attrs.align_self = align_self;
+        root.sublayout.add(element);
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        spans(&root, Direction::Y),
+        [(0.0, 20.0), (80.0, 20.0), (0.0, 100.0)]
+    );
+}
+
+#[test]
+fn stretching_stops_at_the_maximum_size() {
+    let mut root = root((300.0, 100.0), LayoutType::Flexible);
+    root.sublayout.properties.align_y = AlignmentY::Stretch;
+    root.sublayout.properties.flex_grow = false;
+    for max_height in [90.0, 10.0] {
+        let mut element = element(50.0, 20.0);
+        element.attrs.max_size = Some((50.0.into(), max_height.into()));
+        root.sublayout.add(element);
+    }
+    root.sublayout.resize_flexible();
+
+    // elements never shrink to stretch
+    assert_eq!(spans(&root, Direction::Y), [(0.0, 90.0), (0.0, 20.0)]);
+}
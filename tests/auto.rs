@@ -0,0 +1,72 @@
+mod common;
+
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+use common::{element, nested, rects, root};
+
+/// A 300x200 block root holding an auto-sized element, which lays out `contents` in
+/// a sublayout of the given type, followed by a 50x50 element.
+fn auto_sized(variant: LayoutType, contents: &[(SizeUnit, f64)]) -> Element {
+    let mut root = root((300.0, 200.0), LayoutType::Block);
+
+    let mut auto = Element::new(
+        (SizeUnit::Auto, SizeUnit::Auto),
+        (0.0.into(), 0.0.into()),
+        variant,
+    );
+    for (width, height) in contents {
+        auto.sublayout.add(element(width.clone(), *height));
+    }
+    root.sublayout.add(auto);
+    root.sublayout.add(element(50.0, 50.0));
+
+    root
+}
+
+#[test]
+fn auto_fits_a_block_layout() {
+    let root = auto_sized(
+        LayoutType::Block,
+        &[(100.0.into(), 40.0), (80.0.into(), 60.0)],
+    );
+
+    assert_eq!(
+        rects(&root),
+        [(0.0, 0.0, 180.0, 60.0), (180.0, 0.0, 50.0, 50.0)]
+    );
+}
+
+#[test]
+fn auto_fits_a_wrapped_block_layout() {
+    let root = auto_sized(
+        LayoutType::Block,
+        &[(200.0.into(), 40.0), (150.0.into(), 60.0)],
+    );
+
+    // the contents wrap within all of the space available to them
+    assert_eq!(rects(&root)[0], (0.0, 0.0, 200.0, 100.0));
+}
+
+#[test]
+fn auto_fits_a_flexible_layout() {
+    let root = auto_sized(
+        LayoutType::Flexible,
+        &[(100.0.into(), 40.0), (80.0.into(), 60.0)],
+    );
+
+    assert_eq!(
+        rects(&root),
+        [(0.0, 0.0, 180.0, 60.0), (180.0, 0.0, 50.0, 50.0)]
+    );
+    assert_eq!(nested(&root, &[0, 1]).position.0.clone().pixels(), 100.0);
+}
+
+#[test]
+fn fractions_take_all_of_the_space_available() {
+    let root = auto_sized(
+        LayoutType::Flexible,
+        &[(100.0.into(), 40.0), (SizeUnit::Fraction(1.0), 60.0)],
+    );
+
+    assert_eq!(rects(&root)[0], (0.0, 0.0, 300.0, 60.0));
+    assert_eq!(nested(&root, &[0, 1]).size.0.clone().pixels(), 200.0);
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use clemen::layouts::{Direction, LayoutType, element::Element, unit::SizeUnit};
+use common::{element, rects, root, spans};
+
+/// A flexible layout `cells` wide in cell-grid mode, with cells of the given size.
+fn grid(cells: f64, cell_size: (f64, f64)) -> Element {
+    let mut root = root(
+        (cells * cell_size.0, 24.0 * cell_size.1),
+        LayoutType::Flexible,
+    );
+    root.sublayout.cell_grid = true;
+    root.sublayout.cell_size = cell_size;
+
+    root
+}
+
+#[test]
+fn fractions_tile_the_grid() {
+    let mut root = grid(80.0, (1.0, 1.0));
+    for _ in 0..3 {
+        root.sublayout
+            .add(element(SizeUnit::Fraction(1.0), SizeUnit::Cell(24.0)));
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        spans(&root, Direction::X),
+        [(0.0, 27.0), (27.0, 27.0), (54.0, 26.0)]
+    );
+}
+
+#[test]
+fn shrunk_elements_tile_the_grid() {
+    let mut root = grid(80.0, (1.0, 1.0));
+    for _ in 0..3 {
+        root.sublayout
+            .add(element(SizeUnit::Cell(30.0), SizeUnit::Cell(24.0)));
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        spans(&root, Direction::X),
+        [(0.0, 26.0), (26.0, 27.0), (53.0, 27.0)]
+    );
+}
+
+#[test]
+fn grown_elements_tile_the_grid() {
+    let mut root = grid(80.0, (1.0, 1.0));
+    for _ in 0..3 {
+        root.sublayout
+            .add(element(SizeUnit::Cell(10.0), SizeUnit::Cell(24.0)));
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        spans(&root, Direction::X),
+        [(0.0, 27.0), (27.0, 27.0), (54.0, 26.0)]
+    );
+}
+
+#[test]
+fn cells_bigger_than_a_pixel() {
+    let mut root = grid(80.0, (8.0, 16.0));
+    for _ in 0..3 {
+        root.sublayout
+            .add(element(SizeUnit::Fraction(1.0), SizeUnit::Cell(2.0)));
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        rects(&root),
+        [
+            (0.0, 0.0, 216.0, 32.0),
+            (216.0, 0.0, 216.0, 32.0),
+            (432.0, 0.0, 208.0, 32.0)
+        ]
+    );
+}
@@ -0,0 +1,77 @@
+//! Builders and accessors shared by the integration tests.
+
+// every test file is its own crate, and only uses some of these
+#![allow(dead_code)]
+
+use clemen::layouts::{Direction, LayoutType, element::Element, unit::SizeUnit};
+
+/// An element holding a layout of the given size, at the origin.
+pub fn root(size: (f64, f64), variant: LayoutType) -> Element {
+    Element::new(
+        (size.0.into(), size.1.into()),
+        (0.0.into(), 0.0.into()),
+        variant,
+    )
+}
+
+/// A block element of the given size, at the origin.
+pub fn element(width: impl Into<SizeUnit>, height: impl Into<SizeUnit>) -> Element {
+    Element::new(
+        (width.into(), height.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    )
+}
+
+/// Every element in the root's layout, in the order they were added.
+fn elements(root: &Element) -> impl Iterator<Item = &Element> {
+    (0..).map_while(|i| root.sublayout.get(i))
+}
+
+/// The (position, size) of every element along the given direction, in pixels.
+pub fn spans(root: &Element, direction: Direction) -> Vec<(f64, f64)> {
+    elements(root)
+        .map(|element| match direction {
+            Direction::X => (
+                element.position.0.clone().pixels(),
+                element.size.0.clone().pixels(),
+            ),
+            Direction::Y => (
+                element.position.1.clone().pixels(),
+                element.size.1.clone().pixels(),
+            ),
+        })
+        .collect()
+}
+
+/// The (left, top) of every element, in pixels.
+pub fn positions(root: &Element) -> Vec<(f64, f64)> {
+    elements(root)
+        .map(|element| {
+            (
+                element.position.0.clone().pixels(),
+                element.position.1.clone().pixels(),
+            )
+        })
+        .collect()
+}
+
+/// The (left, top, width, height) of every element, in pixels.
+pub fn rects(root: &Element) -> Vec<(f64, f64, f64, f64)> {
+    elements(root)
+        .map(|element| {
+            (
+                element.position.0.clone().pixels(),
+                element.position.1.clone().pixels(),
+                element.size.0.clone().pixels(),
+                element.size.1.clone().pixels(),
+            )
+        })
+        .collect()
+}
+
+/// The element found by following `path` down through each element's sublayout.
+pub fn nested<'a>(root: &'a Element, path: &[usize]) -> &'a Element {
+    path.iter()
+        .fold(root, |element, &i| element.sublayout.get(i).unwrap())
+}
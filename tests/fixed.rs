@@ -0,0 +1,137 @@
+use clemen::layouts::{
+    AlignmentX, LayoutProperties, LayoutType,
+    element::{Element, Vector2},
+    fixed::Fixed,
+    scalar::Scalar,
+    unit::SizeUnit,
+};
+
+fn px(value: f64) -> SizeUnit<Fixed> {
+    SizeUnit::Pixel(Fixed::from_f64(value))
+}
+
+fn origin() -> Vector2<Fixed> {
+    (px(0.0), px(0.0))
+}
+
+fn root(width: f64, height: f64, variant: LayoutType) -> Element<Fixed> {
+    Element::new((px(width), px(height)), origin(), variant)
+}
+
+/// FNV-1a, since the standard library's hasher isn't guaranteed to be stable.
+fn hash(element: &Element<Fixed>) -> u64 {
+    element
+        .html()
+        .bytes()
+        .fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+}
+
+#[test]
+fn block_wrapping() {
+    let mut root = root(250.0, 400.0, LayoutType::Block);
+
+    for i in 0..7 {
+        root.sublayout.add(Element::new(
+            (px(60.0 + i as f64 * 7.3), px(20.0 + i as f64 * 3.1)),
+            origin(),
+            LayoutType::Block,
+        ));
+    }
+
+    assert_eq!(hash(&root), 3174507812046660601);
+}
+
+#[test]
+fn flexible_shrink() {
+    let mut root = root(200.0, 100.0, LayoutType::Flexible);
+    root.sublayout.properties = LayoutProperties {
+        offset: px(0.0),
+        ..Default::default()
+    };
+
+    for _ in 0..7 {
+        root.sublayout.add(Element::new(
+            (px(100.0), px(100.0)),
+            origin(),
+            LayoutType::Block,
+        ));
+    }
+
+    root.sublayout.resize_flexible();
+    assert_eq!(hash(&root), 1629151108384923945);
+}
+
+#[test]
+fn flexible_fractions() {
+    let mut root = root(301.0, 100.0, LayoutType::Flexible);
+
+    root.sublayout.add(Element::new(
+        (px(50.0), px(100.0)),
+        origin(),
+        LayoutType::Block,
+    ));
+
+    for weight in [1.0, 2.0, 3.0] {
+        root.sublayout.add(Element::new(
+            (SizeUnit::Fraction(Fixed::from_f64(weight)), px(100.0)),
+            origin(),
+            LayoutType::Block,
+        ));
+    }
+
+    root.sublayout.resize_flexible();
+    assert_eq!(hash(&root), 925301314780087250);
+}
+
+#[test]
+fn flexible_center() {
+    let mut root = root(333.0, 100.0, LayoutType::Flexible);
+    root.sublayout.properties = LayoutProperties {
+        align_x: AlignmentX::Center,
+        flex_grow: false,
+        ..Default::default()
+    };
+
+    for _ in 0..3 {
+        root.sublayout.add(Element::new(
+            (px(41.0), px(41.0)),
+            origin(),
+            LayoutType::Block,
+        ));
+    }
+
+    root.sublayout.resize_flexible();
+    assert_eq!(hash(&root), 13252590816394427493);
+}
+
+#[test]
+fn relative_units() {
+    let mut root = root(1000.0, 400.0, LayoutType::Block);
+    root.sublayout.properties = LayoutProperties {
+        font_size: Some(px(13.0)),
+        ..Default::default()
+    };
+
+    root.sublayout.add(Element::new(
+        (
+            SizeUnit::Percent(Fixed::from_f64(30.0)).clamp(px(200.0), px(400.0)),
+            SizeUnit::Em(Fixed::from_f64(2.5)),
+        ),
+        origin(),
+        LayoutType::Block,
+    ));
+
+    root.sublayout.add(Element::new(
+        (
+            SizeUnit::Percent(Fixed::from_f64(60.0)) - px(48.0),
+            SizeUnit::Vh(Fixed::from_f64(33.3)),
+        ),
+        origin(),
+        LayoutType::Block,
+    ));
+
+    root.resize((px(777.0), px(333.0)));
+    assert_eq!(hash(&root), 18311809443705036758);
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use clemen::layouts::{Direction, LayoutType, unit::SizeUnit};
+use common::{element, root, spans};
+
+#[test]
+fn free_space_follows_grow_factors() {
+    let mut root = root((300.0, 50.0), LayoutType::Flexible);
+    for flex_grow in [1.0, 2.0, 0.0] {
+        let mut element = element(50.0, 50.0);
+        element.attrs.flex_grow = flex_grow;
+        root.sublayout.add(element);
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        spans(&root, Direction::X),
+        [(0.0, 100.0), (100.0, 150.0), (250.0, 50.0)]
+    );
+}
+
+#[test]
+fn overflow_follows_shrink_factors() {
+    let mut root = root((200.0, 50.0), LayoutType::Flexible);
+    for flex_shrink in [1.0, 3.0] {
+        let mut element = element(150.0, 50.0);
+        element.attrs.flex_shrink = flex_shrink;
+        root.sublayout.add(element);
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(spans(&root, Direction::X), [(0.0, 125.0), (125.0, 75.0)]);
+}
+
+#[test]
+fn larger_elements_shrink_more() {
+    let mut root = root((200.0, 50.0), LayoutType::Flexible);
+    for width in [200.0, 100.0, 100.0] {
+        root.sublayout.add(element(width, 50.0));
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        spans(&root, Direction::X),
+        [(0.0, 100.0), (100.0, 50.0), (150.0, 50.0)]
+    );
+}
+
+#[test]
+fn elements_without_a_shrink_factor_keep_their_size() {
+    let mut root = root((200.0, 50.0), LayoutType::Flexible);
+    let mut fixed = element(150.0, 50.0);
+    fixed.attrs.flex_shrink = 0.0;
+    root.sublayout.add(fixed);
+    root.sublayout.add(element(150.0, 50.0));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(spans(&root, Direction::X), [(0.0, 150.0), (150.0, 50.0)]);
+}
+
+#[test]
+fn bases_replace_the_authored_size() {
+    let mut root = root((300.0, 50.0), LayoutType::Flexible);
+    root.sublayout.properties.flex_grow = false;
+    for flex_basis in [100.0.into(), SizeUnit::Percent(50.0)] {
+        let mut element = element(10.0, 50.0);
+        element.attrs.flex_basis = flex_basis;
+        root.sublayout.add(element);
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(spans(&root, Direction::X), [(0.0, 100.0), (100.0, 150.0)]);
+}
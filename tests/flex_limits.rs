@@ -0,0 +1,65 @@
+mod common;
+
+use clemen::layouts::{Direction, LayoutType, element::Element};
+use common::{element, root, spans};
+
+/// An element without the default minimum size, so only the given limits apply.
+fn limited(width: f64, min: Option<f64>, max: Option<f64>) -> Element {
+    let mut element = element(width, 100.0);
+    element.attrs.min_size = min.map(|min| (min.into(), 0.0.into()));
+    element.attrs.max_size = max.map(|max| (max.into(), 1000.0.into()));
+
+    element
+}
+
+#[test]
+fn shrinking_stops_at_the_minimum() {
+    let mut root = root((300.0, 100.0), LayoutType::Flexible);
+    root.sublayout.add(limited(200.0, Some(150.0), None));
+    root.sublayout.add(limited(200.0, None, None));
+    root.sublayout.add(limited(200.0, None, None));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        spans(&root, Direction::X),
+        [(0.0, 150.0), (150.0, 75.0), (225.0, 75.0)]
+    );
+}
+
+#[test]
+fn growing_stops_at_the_maximum() {
+    let mut root = root((300.0, 100.0), LayoutType::Flexible);
+    root.sublayout.add(limited(50.0, None, Some(60.0)));
+    root.sublayout.add(limited(50.0, None, None));
+    root.sublayout.add(limited(50.0, None, None));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        spans(&root, Direction::X),
+        [(0.0, 60.0), (60.0, 120.0), (180.0, 120.0)]
+    );
+}
+
+#[test]
+fn clamping_a_shrink_never_grows_siblings() {
+    let mut root = root((100.0, 100.0), LayoutType::Flexible);
+    root.sublayout.properties.flex_grow = false;
+    root.sublayout.add(limited(80.0, None, Some(30.0)));
+    root.sublayout.add(limited(40.0, None, None));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(spans(&root, Direction::X), [(0.0, 30.0), (30.0, 40.0)]);
+    // nothing had to shrink to fit
+    assert!(root.sublayout.overflowed().is_empty());
+}
+
+#[test]
+fn elements_which_arent_resized_are_clamped() {
+    let mut root = root((100.0, 100.0), LayoutType::Flexible);
+    root.sublayout.properties.flex_grow = false;
+    root.sublayout.add(limited(50.0, None, Some(30.0)));
+    root.sublayout.add(limited(10.0, Some(20.0), None));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(spans(&root, Direction::X), [(0.0, 30.0), (30.0, 20.0)]);
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use clemen::layouts::{LayoutType, unit::SizeUnit};
+use common::{element, nested, root};
+
+/// A 400x400 root with a 20px font, holding a block which holds a `width` wide
+/// element. The middle block sets its own font size when one is given.
+fn nested_width(font_size: Option<SizeUnit>, width: SizeUnit) -> f64 {
+    let mut root = root((400.0, 400.0), LayoutType::Block);
+    root.sublayout.properties.font_size = Some(20.0.into());
+
+    let mut middle = element(200.0, 200.0);
+    middle.sublayout.properties.font_size = font_size;
+    middle.sublayout.add(element(width, 10.0));
+    root.sublayout.add(middle);
+
+    nested(&root, &[0, 0]).size.0.clone().pixels()
+}
+
+#[test]
+fn font_sizes_are_inherited() {
+    assert_eq!(nested_width(None, SizeUnit::Em(2.0)), 40.0);
+}
+
+#[test]
+fn font_sizes_can_be_overridden() {
+    assert_eq!(nested_width(Some(30.0.into()), SizeUnit::Em(2.0)), 60.0);
+}
+
+#[test]
+fn relative_font_sizes_are_relative_to_the_inherited_one() {
+    assert_eq!(
+        nested_width(Some(SizeUnit::Em(1.5)), SizeUnit::Em(2.0)),
+        60.0
+    );
+}
+
+#[test]
+fn rem_is_always_the_root_font_size() {
+    assert_eq!(nested_width(None, SizeUnit::Rem(2.0)), 40.0);
+    assert_eq!(nested_width(Some(30.0.into()), SizeUnit::Rem(2.0)), 40.0);
+    assert_eq!(
+        nested_width(Some(SizeUnit::Em(1.5)), SizeUnit::Rem(2.0)),
+        40.0
+    );
+}
+
+#[test]
+fn the_default_font_size_is_16px() {
+    let mut root = root((400.0, 400.0), LayoutType::Block);
+    root.sublayout
+        .add(element(SizeUnit::Em(1.0), SizeUnit::Rem(2.0)));
+
+    assert_eq!(
+        (
+            nested(&root, &[0]).size.0.clone().pixels(),
+            nested(&root, &[0]).size.1.clone().pixels()
+        ),
+        (16.0, 32.0)
+    );
+}
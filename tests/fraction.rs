@@ -0,0 +1,59 @@
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+
+fn element(width: SizeUnit, height: f64) -> Element {
+    Element::new(
+        (width, height.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Block,
+    )
+}
+
+/// The (x, width) of the element at the given index.
+fn span(root: &Element, idx: usize) -> (f64, f64) {
+    let element = root.sublayout.get(idx).unwrap();
+    (
+        element.position.0.clone().pixels(),
+        element.size.0.clone().pixels(),
+    )
+}
+
+fn root() -> Element {
+    let mut root = Element::new(
+        (400.0.into(), 100.0.into()),
+        (0.0.into(), 0.0.into()),
+        LayoutType::Flexible,
+    );
+
+    root.sublayout.add(element(100.0.into(), 100.0));
+    root.sublayout.add(element(SizeUnit::Fraction(1.0), 100.0));
+    root.sublayout.add(element(SizeUnit::Fraction(2.0), 100.0));
+    root
+}
+
+#[test]
+fn fractions_share_leftover_space_without_resizing() {
+    let root = root();
+
+    assert_eq!(span(&root, 0), (0.0, 100.0));
+    assert_eq!(span(&root, 1), (100.0, 100.0));
+    assert_eq!(span(&root, 2), (200.0, 200.0));
+}
+
+#[test]
+fn fractions_follow_the_parent_size() {
+    let mut root = root();
+    root.resize((700.0.into(), 100.0.into()));
+
+    assert_eq!(span(&root, 0), (0.0, 100.0));
+    assert_eq!(span(&root, 1), (100.0, 200.0));
+    assert_eq!(span(&root, 2), (300.0, 400.0));
+}
+
+#[test]
+fn resizing_keeps_fraction_sizes() {
+    let mut root = root();
+    root.sublayout.resize_flexible();
+
+    assert_eq!(span(&root, 1), (100.0, 100.0));
+    assert_eq!(span(&root, 2), (200.0, 200.0));
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use clemen::layouts::{Direction, LayoutType};
+use common::{element, rects, root};
+
+#[test]
+fn rows_and_columns_have_their_own_gaps() {
+    let mut root = root((330.0, 400.0), LayoutType::Block);
+    root.sublayout.properties.row_gap = Some(30.0.into());
+    root.sublayout.properties.column_gap = Some(15.0.into());
+    for _ in 0..4 {
+        root.sublayout.add(element(100.0, 60.0));
+    }
+
+    // the last element in a row touches the edge, since there's no gap after it
+    assert_eq!(
+        rects(&root),
+        [
+            (0.0, 0.0, 100.0, 60.0),
+            (115.0, 0.0, 100.0, 60.0),
+            (230.0, 0.0, 100.0, 60.0),
+            (0.0, 90.0, 100.0, 60.0)
+        ]
+    );
+}
+
+#[test]
+fn gaps_override_the_offset() {
+    let mut root = root((100.0, 100.0), LayoutType::Block);
+    root.sublayout.properties.offset = 50.0.into();
+    root.sublayout.properties.column_gap = Some(5.0.into());
+    for _ in 0..3 {
+        root.sublayout.add(element(40.0, 10.0));
+    }
+
+    // the rows still use the offset
+    assert_eq!(
+        rects(&root),
+        [
+            (0.0, 0.0, 40.0, 10.0),
+            (45.0, 0.0, 40.0, 10.0),
+            (0.0, 60.0, 40.0, 10.0)
+        ]
+    );
+}
+
+#[test]
+fn growing_leaves_room_for_gaps() {
+    let mut root = root((100.0, 100.0), LayoutType::Flexible);
+    root.sublayout.properties.column_gap = Some(10.0.into());
+    root.sublayout.add(element(20.0, 10.0));
+    root.sublayout.add(element(20.0, 10.0));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        rects(&root),
+        [(0.0, 0.0, 45.0, 10.0), (55.0, 0.0, 45.0, 10.0)]
+    );
+}
+
+#[test]
+fn vertical_layouts_use_the_row_gap_along_their_main_axis() {
+    let mut root = root((100.0, 100.0), LayoutType::Flexible);
+    root.sublayout.properties.row_gap = Some(10.0.into());
+    root.sublayout.properties.column_gap = Some(99.0.into());
+    root.sublayout.properties.flex_grow = false;
+    root.sublayout.direction = Direction::Y;
+    root.sublayout.add(element(20.0, 20.0));
+    root.sublayout.add(element(20.0, 20.0));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        rects(&root),
+        [(0.0, 0.0, 20.0, 20.0), (0.0, 30.0, 20.0, 20.0)]
+    );
+}
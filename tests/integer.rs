@@ -0,0 +1,52 @@
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+
+/// A flexible row 100 pixels wide holding one element per given width.
+fn row(widths: &[i32]) -> Element<i32> {
+    let mut root = Element::new(
+        (100.into(), 50.into()),
+        (0.into(), 0.into()),
+        LayoutType::Flexible,
+    );
+
+    for &width in widths {
+        root.sublayout.add(Element::new(
+            (width.into(), 50.into()),
+            (0.into(), 0.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    root.sublayout.resize_flexible();
+    root
+}
+
+fn widths(root: &Element<i32>, count: usize) -> Vec<i32> {
+    (0..count)
+        .map(|idx| root.sublayout.get(idx).unwrap().size.0.clone().pixels())
+        .collect()
+}
+
+#[test]
+fn shrunk_integer_shares_fill_the_parent() {
+    assert_eq!(widths(&row(&[50, 50, 50]), 3), [33, 33, 34]);
+}
+
+#[test]
+fn grown_integer_shares_fill_the_parent() {
+    assert_eq!(widths(&row(&[10, 10, 10]), 3), [34, 33, 33]);
+}
+
+#[test]
+fn integer_fractions_fill_the_parent() {
+    let mut root = row(&[]);
+    for _ in 0..3 {
+        root.sublayout.add(Element::new(
+            (SizeUnit::Fraction(1), 50.into()),
+            (0.into(), 0.into()),
+            LayoutType::Block,
+        ));
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(widths(&root, 3), [34, 33, 33]);
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use clemen::layouts::{Justify, LayoutType, Overflow, element::Element};
+use common::{element, positions, root};
+
+/// A wrapping flexible layout, which doesn't grow its elements.
+fn wrapping(width: f64, justify: Justify) -> Element {
+    let mut root = root((width, 100.0), LayoutType::Flexible);
+    root.sublayout.overflow = Overflow::Wrap;
+    root.sublayout.properties.flex_grow = false;
+    root.sublayout.properties.justify = justify;
+
+    root
+}
+
+#[test]
+fn rows_starting_at_the_same_place_are_justified_separately() {
+    let mut root = wrapping(100.0, Justify::Center);
+    for _ in 0..3 {
+        root.sublayout.add(element(80.0, 20.0));
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(positions(&root), [(10.0, 0.0), (10.0, 20.0), (10.0, 40.0)]);
+}
+
+#[test]
+fn each_row_is_justified_to_its_own_free_space() {
+    let mut root = wrapping(100.0, Justify::End);
+    for width in [30.0, 30.0, 60.0] {
+        root.sublayout.add(element(width, 20.0));
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(positions(&root), [(40.0, 0.0), (70.0, 0.0), (40.0, 20.0)]);
+}
+
+#[test]
+fn space_is_shared_between_elements() {
+    let cases = [
+        (Justify::SpaceBetween, [0.0, 50.0, 100.0]),
+        (Justify::SpaceAround, [10.0, 50.0, 90.0]),
+        (Justify::SpaceEvenly, [15.0, 50.0, 85.0]),
+    ];
+
+    for (justify, lefts) in cases {
+        let mut root = wrapping(120.0, justify.clone());
+        for _ in 0..3 {
+            root.sublayout.add(element(20.0, 20.0));
+        }
+        root.sublayout.resize_flexible();
+
+        let found: Vec<f64> = positions(&root).iter().map(|p| p.0).collect();
+        assert_eq!(found, lefts, "{justify:?}");
+    }
+}
+
+#[test]
+fn resizing_a_row_leaves_the_next_in_place() {
+    let mut root = wrapping(100.0, Justify::Start);
+    for _ in 0..3 {
+        let mut element = element(80.0, 20.0);
+        element.attrs.min_size = Some((90.0.into(), 0.0.into()));
+        root.sublayout.add(element);
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(positions(&root), [(0.0, 0.0), (0.0, 20.0), (0.0, 40.0)]);
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use clemen::layouts::{Direction, LayoutType, element::Element};
+use common::{element, root, spans};
+
+fn ordered(width: f64, order: i32) -> Element {
+    let mut element = element(width, 100.0);
+    element.attrs.order = order;
+
+    element
+}
+
+#[test]
+fn elements_are_placed_by_order_but_keep_their_index() {
+    let mut root = root((400.0, 100.0), LayoutType::Block);
+    root.sublayout.add(ordered(50.0, 0));
+    root.sublayout.add(ordered(100.0, 0));
+    root.sublayout.add(ordered(75.0, -1));
+
+    assert_eq!(
+        spans(&root, Direction::X),
+        [(75.0, 50.0), (125.0, 100.0), (0.0, 75.0)]
+    );
+}
+
+#[test]
+fn equal_orders_keep_insertion_order() {
+    let mut root = root((400.0, 100.0), LayoutType::Block);
+    root.sublayout.add(ordered(50.0, 1));
+    root.sublayout.add(ordered(100.0, 0));
+    root.sublayout.add(ordered(75.0, 1));
+
+    assert_eq!(
+        spans(&root, Direction::X),
+        [(100.0, 50.0), (0.0, 100.0), (150.0, 75.0)]
+    );
+}
+
+#[test]
+fn flexible_layouts_resize_in_visual_order() {
+    let mut root = root((400.0, 100.0), LayoutType::Flexible);
+    root.sublayout.add(ordered(300.0, 1));
+    root.sublayout.add(ordered(300.0, 0));
+    root.sublayout.resize_flexible();
+
+    // indices are unchanged after shrinking too
+    assert_eq!(spans(&root, Direction::X), [(200.0, 200.0), (0.0, 200.0)]);
+    assert_eq!(root.sublayout.overflowed(), [0, 1]);
+}
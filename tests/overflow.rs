@@ -0,0 +1,143 @@
+mod common;
+
+use clemen::layouts::{Direction, LayoutType, Overflow, element::Element, unit::SizeUnit};
+use common::{element, root, spans};
+
+/// A 100x40 layout holding four 50x40 elements, twice as many as fit in a row.
+fn overflowing(variant: LayoutType, overflow: Overflow) -> Element {
+    let mut root = root((100.0, 40.0), variant.clone());
+    root.sublayout.properties.flex_grow = false;
+    root.sublayout.overflow = overflow;
+    for _ in 0..4 {
+        root.sublayout.add(element(50.0, 40.0));
+    }
+
+    if variant == LayoutType::Flexible {
+        root.sublayout.resize_flexible();
+    }
+
+    root
+}
+
+fn hidden(root: &Element) -> Vec<usize> {
+    (0..4).filter(|&i| root.sublayout.is_hidden(i)).collect()
+}
+
+fn widths(root: &Element) -> Vec<f64> {
+    spans(root, Direction::X)
+        .iter()
+        .map(|span| span.1)
+        .collect()
+}
+
+#[test]
+fn wrap() {
+    let root = overflowing(LayoutType::Flexible, Overflow::Wrap);
+    let layout = &root.sublayout;
+
+    assert_eq!(layout.overflowed(), [2, 3]);
+    assert!(hidden(&root).is_empty());
+    assert_eq!(layout.clip_region(), None);
+    assert_eq!(layout.content_size(), (100.0, 80.0));
+}
+
+#[test]
+fn shrink() {
+    let root = overflowing(LayoutType::Flexible, Overflow::Shrink);
+    let layout = &root.sublayout;
+
+    assert_eq!(layout.overflowed(), [0, 1, 2, 3]);
+    assert!(hidden(&root).is_empty());
+    assert_eq!(layout.clip_region(), None);
+    assert_eq!(layout.content_size(), (100.0, 40.0));
+    assert_eq!(widths(&root), [25.0; 4]);
+}
+
+#[test]
+fn clip() {
+    let root = overflowing(LayoutType::Flexible, Overflow::Clip);
+    let layout = &root.sublayout;
+
+    assert_eq!(layout.overflowed(), [2, 3]);
+    assert!(hidden(&root).is_empty());
+    assert_eq!(layout.clip_region(), Some(((0.0, 0.0), (100.0, 40.0))));
+    assert_eq!(layout.content_size(), (200.0, 40.0));
+    assert_eq!(widths(&root), [50.0; 4]);
+}
+
+#[test]
+fn scroll() {
+    let root = overflowing(LayoutType::Flexible, Overflow::Scroll);
+    let layout = &root.sublayout;
+
+    assert_eq!(layout.overflowed(), [2, 3]);
+    assert!(hidden(&root).is_empty());
+    assert_eq!(layout.clip_region(), None);
+    assert_eq!(layout.content_size(), (200.0, 40.0));
+}
+
+#[test]
+fn hide() {
+    let root = overflowing(LayoutType::Flexible, Overflow::Hide);
+    let layout = &root.sublayout;
+
+    assert_eq!(layout.overflowed(), [2, 3]);
+    assert_eq!(hidden(&root), [2, 3]);
+    assert_eq!(layout.clip_region(), Some(((0.0, 0.0), (100.0, 40.0))));
+    assert_eq!(layout.content_size(), (100.0, 40.0));
+}
+
+#[test]
+fn block_layouts_clip_instead_of_shrinking() {
+    let root = overflowing(LayoutType::Block, Overflow::Shrink);
+    let layout = &root.sublayout;
+
+    assert_eq!(layout.overflowed(), [2, 3]);
+    assert!(hidden(&root).is_empty());
+    assert_eq!(layout.clip_region(), Some(((0.0, 0.0), (100.0, 40.0))));
+    assert_eq!(layout.content_size(), (200.0, 40.0));
+    assert_eq!(widths(&root), [50.0; 4]);
+}
+
+#[test]
+fn elements_which_fit_again_are_no_longer_overflowed() {
+    let mut root = root((100.0, 40.0), LayoutType::Flexible);
+    root.sublayout.add(element(80.0, 40.0));
+    root.sublayout.add(element(80.0, 40.0));
+    root.sublayout.resize_flexible();
+    assert_eq!(root.sublayout.overflowed(), [0, 1]);
+
+    root.sublayout.size = (400.0.into(), 40.0.into());
+    root.sublayout.resize_flexible();
+    assert!(root.sublayout.overflowed().is_empty());
+}
+
+#[test]
+fn wrapped_rows_grow_separately() {
+    let mut root = root((100.0, 80.0), LayoutType::Flexible);
+    root.sublayout.overflow = Overflow::Wrap;
+    for width in [40.0, 30.0, 70.0] {
+        root.sublayout.add(element(width, 40.0));
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        spans(&root, Direction::X),
+        [(0.0, 55.0), (55.0, 45.0), (0.0, 100.0)]
+    );
+}
+
+#[test]
+fn wrapped_rows_share_their_own_fractions() {
+    let mut root = root((100.0, 80.0), LayoutType::Flexible);
+    root.sublayout.overflow = Overflow::Wrap;
+    root.sublayout.add(element(40.0, 40.0));
+    root.sublayout.add(element(SizeUnit::Fraction(1.0), 40.0));
+    root.sublayout.add(element(70.0, 40.0));
+    root.sublayout.add(element(SizeUnit::Fraction(1.0), 40.0));
+
+    assert_eq!(
+        spans(&root, Direction::X),
+        [(0.0, 40.0), (40.0, 60.0), (0.0, 70.0), (70.0, 30.0)]
+    );
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use clemen::layouts::{
+    AlignmentY, LayoutType, Padding,
+    element::{Element, PositionStyle},
+    unit::SizeUnit,
+};
+use common::{element, rects, root};
+
+/// A layout with 10 pixels of padding on the top and bottom, and 20 on the sides.
+fn padded(size: (f64, f64), variant: LayoutType) -> Element {
+    let mut root = root(size, variant);
+    root.sublayout.properties.padding = Padding {
+        top: 10.0.into(),
+        right: 20.0.into(),
+        bottom: 10.0.into(),
+        left: 20.0.into(),
+    };
+
+    root
+}
+
+#[test]
+fn the_content_box_is_inside_of_the_padding() {
+    let root = padded((300.0, 100.0), LayoutType::Block);
+
+    assert_eq!(root.sublayout.content_box(), (260.0, 80.0));
+}
+
+#[test]
+fn elements_grow_and_stretch_inside_of_the_padding() {
+    let mut root = padded((300.0, 100.0), LayoutType::Flexible);
+    root.sublayout.properties.align_y = AlignmentY::Stretch;
+    root.sublayout.add(element(50.0, 50.0));
+    root.sublayout.add(element(50.0, 50.0));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        rects(&root),
+        [(20.0, 10.0, 130.0, 80.0), (150.0, 10.0, 130.0, 80.0)]
+    );
+}
+
+#[test]
+fn elements_shrink_to_fit_inside_of_the_padding() {
+    let mut root = root((100.0, 100.0), LayoutType::Flexible);
+    root.sublayout.properties.padding = Padding::all(10.0.into());
+    root.sublayout.add(element(80.0, 50.0));
+    root.sublayout.add(element(80.0, 50.0));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(
+        rects(&root),
+        [(10.0, 10.0, 40.0, 50.0), (50.0, 10.0, 40.0, 50.0)]
+    );
+}
+
+#[test]
+fn rows_wrap_at_the_padding() {
+    let mut root = padded((300.0, 200.0), LayoutType::Block);
+    for _ in 0..3 {
+        root.sublayout.add(element(130.0, 40.0));
+    }
+
+    assert_eq!(
+        rects(&root),
+        [
+            (20.0, 10.0, 130.0, 40.0),
+            (150.0, 10.0, 130.0, 40.0),
+            (20.0, 50.0, 130.0, 40.0)
+        ]
+    );
+    assert_eq!(root.sublayout.content_size(), (300.0, 100.0));
+}
+
+#[test]
+fn percentages_are_relative_to_the_content_box() {
+    let mut root = padded((300.0, 100.0), LayoutType::Block);
+    root.sublayout.add(element(SizeUnit::Percent(50.0), 10.0));
+
+    assert_eq!(rects(&root), [(20.0, 10.0, 130.0, 10.0)]);
+}
+
+#[test]
+fn absolute_elements_ignore_the_padding() {
+    let mut root = padded((300.0, 100.0), LayoutType::Block);
+    let mut absolute = element(10.0, 10.0);
+    absolute.attrs.style = PositionStyle::Absolute;
+    root.sublayout.add(absolute);
+
+    assert_eq!(rects(&root), [(0.0, 0.0, 10.0, 10.0)]);
+}
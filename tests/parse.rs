@@ -0,0 +1,205 @@
+use clemen::layouts::{
+    LayoutType,
+    element::Element,
+    fixed::Fixed,
+    parse::ParseUnitError,
+    scalar::Scalar,
+    unit::{Expression, SizeUnit},
+};
+
+fn round_trip<S: Scalar>(unit: SizeUnit<S>) {
+    let string = unit.to_string();
+    assert_eq!(string.parse::<SizeUnit<S>>(), Ok(unit), "{string}");
+}
+
+#[test]
+fn parse_suffixes() {
+    assert_eq!("12px".parse(), Ok(SizeUnit::Pixel(12.0)));
+    assert_eq!("12".parse(), Ok(SizeUnit::Pixel(12.0)));
+    assert_eq!("50%".parse(), Ok(SizeUnit::Percent(50.0)));
+    assert_eq!(" 1.5em ".parse(), Ok(SizeUnit::Em(1.5)));
+    assert_eq!("-.25rem".parse(), Ok(SizeUnit::Rem(-0.25)));
+    assert_eq!("auto".parse(), Ok(SizeUnit::<f64>::Auto));
+    assert_eq!("3cell".parse(), Ok(SizeUnit::Cell(3)));
+}
+
+#[test]
+fn parse_expressions() {
+    assert_eq!(
+        "calc(100% - 2 * 8px)".parse(),
+        Ok(SizeUnit::Calc(Box::new(Expression::Sub(
+            SizeUnit::Percent(100.0),
+            SizeUnit::Calc(Box::new(Expression::Mul(
+                SizeUnit::Pixel(2.0),
+                SizeUnit::Pixel(8.0)
+            ))),
+        ))))
+    );
+
+    assert_eq!(
+        "clamp(200px, 30%, 400px)".parse(),
+        Ok(SizeUnit::Percent(30.0).clamp(200.0.into(), 400.0.into()))
+    );
+}
+
+#[test]
+fn round_trip_every_unit() {
+    let units = [
+        SizeUnit::Pixel(12.0),
+        SizeUnit::Pixel(-0.1),
+        SizeUnit::Percent(50.0),
+        SizeUnit::Fraction(1.0),
+        SizeUnit::Em(1.5),
+        SizeUnit::Rem(2.0),
+        SizeUnit::Vw(10.0),
+        SizeUnit::Vh(10.0),
+        SizeUnit::Vmin(5.0),
+        SizeUnit::Vmax(5.0),
+        SizeUnit::Millimetre(25.4),
+        SizeUnit::Point(72.0),
+        SizeUnit::Inch(1.0),
+        SizeUnit::Cell(3.0),
+        SizeUnit::Auto,
+        SizeUnit::Calc(Box::new(Expression::Add(
+            SizeUnit::Pixel(1.0),
+            SizeUnit::Pixel(2.0),
+        ))),
+        SizeUnit::Percent(100.0) - SizeUnit::Em(2.0) * SizeUnit::Vw(3.0),
+        (SizeUnit::Percent(100.0) - SizeUnit::Em(2.0)) / SizeUnit::Vh(4.0),
+        SizeUnit::Percent(50.0)
+            .min(SizeUnit::Pixel(300.0))
+            .max(SizeUnit::Rem(10.0)),
+        SizeUnit::Vw(30.0).clamp(SizeUnit::Pixel(-10.0), SizeUnit::Percent(100.0)),
+    ];
+
+    for unit in units {
+        round_trip(unit);
+    }
+}
+
+#[test]
+fn parse_exponents() {
+    assert_eq!("1e3px".parse(), Ok(SizeUnit::Pixel(1000.0)));
+    assert_eq!("1.5E-1em".parse(), Ok(SizeUnit::Em(0.15)));
+    assert_eq!("-2e+2".parse(), Ok(SizeUnit::Pixel(-200.0)));
+    // an `e` without digits after it is the start of a suffix
+    assert_eq!("2em".parse(), Ok(SizeUnit::Em(2.0)));
+    assert_eq!(
+        "calc(1e2% - 2em)".parse(),
+        Ok(SizeUnit::Percent(100.0) - SizeUnit::Em(2.0))
+    );
+}
+
+#[test]
+fn round_trip_non_finite() {
+    round_trip(SizeUnit::Pixel(f64::INFINITY));
+    round_trip(SizeUnit::Percent(f64::NEG_INFINITY));
+    round_trip(SizeUnit::Vw(f32::INFINITY));
+    round_trip(SizeUnit::Percent(100.0).min(SizeUnit::Pixel(f64::INFINITY)));
+
+    assert!(matches!(
+        "NaNpx".parse(),
+        Ok(SizeUnit::Pixel(value)) if f64::is_nan(value)
+    ));
+}
+
+#[test]
+fn round_trip_scalars() {
+    round_trip(SizeUnit::Percent(0.1f32));
+    round_trip(SizeUnit::Em(-7i32));
+    round_trip(SizeUnit::Vh(Fixed::from_f64(33.3)));
+}
+
+#[test]
+fn parse_errors() {
+    let parse = |s: &str| s.parse::<SizeUnit>().unwrap_err();
+
+    assert_eq!(parse("  "), ParseUnitError::Empty);
+    assert_eq!(parse("12pz"), ParseUnitError::UnknownUnit("pz".into()));
+    assert_eq!(
+        parse("1.2.3px"),
+        ParseUnitError::InvalidNumber("1.2.3".into())
+    );
+    assert_eq!(
+        parse("floor(1px)"),
+        ParseUnitError::UnknownFunction("floor".into())
+    );
+    assert_eq!(
+        parse("calc(1px + 2px"),
+        ParseUnitError::Expected {
+            expected: "\",\" or \")\"",
+            found: None,
+            position: 14,
+        }
+    );
+    assert_eq!(
+        parse("clamp(1px, 2px)"),
+        ParseUnitError::ArgumentCount {
+            function: "clamp".into(),
+            expected: "3",
+            found: 2,
+        }
+    );
+    assert_eq!(
+        parse("calc(100% - 1fr)"),
+        ParseUnitError::NotCalculable("1fr".into())
+    );
+    assert_eq!(
+        parse("min(auto, 10px)"),
+        ParseUnitError::NotCalculable("auto".into())
+    );
+    assert_eq!(
+        parse("calc(2fr)").to_string(),
+        "2fr cannot be used in an expression"
+    );
+    assert_eq!(
+        parse("12px 3px").to_string(),
+        "expected the end of the string at 5, found '3'"
+    );
+}
+
+#[test]
+#[should_panic(expected = "1fr cannot be used in an expression")]
+fn fractions_in_expressions() {
+    let _ = SizeUnit::Percent(100.0) - SizeUnit::Fraction(1.0);
+}
+
+#[test]
+#[should_panic(expected = "auto cannot be used in an expression")]
+fn auto_in_expressions() {
+    let _ = SizeUnit::Auto.clamp(SizeUnit::Pixel(10.0), SizeUnit::Percent(50.0));
+}
+
+/// Lay out a single element as wide as `width`, and give back its computed width.
+fn laid_out_width<S: Scalar>(width: &str) -> S {
+    let mut root: Element<S> = Element::new(
+        (S::from_f64(100.0).into(), S::from_f64(100.0).into()),
+        (S::ZERO.into(), S::ZERO.into()),
+        LayoutType::Block,
+    );
+
+    root.sublayout.add(Element::new(
+        (width.parse().unwrap(), S::from_f64(10.0).into()),
+        (S::ZERO.into(), S::ZERO.into()),
+        LayoutType::Block,
+    ));
+
+    root.sublayout.get(0).unwrap().size.0.clone().pixels()
+}
+
+#[test]
+fn division_by_zero() {
+    assert_eq!(laid_out_width::<i32>("calc(10px / 0)"), 0);
+    assert_eq!(
+        laid_out_width::<Fixed>("calc(50% / (1px - 1px))"),
+        Fixed::ZERO
+    );
+    assert_eq!(laid_out_width::<f64>("calc(10px / 0)"), 0.0);
+    assert_eq!(laid_out_width::<i32>("calc(10px / 2)"), 5);
+
+    assert_eq!(SizeUnit::Pixel(10) / 0, 0);
+    assert_eq!(
+        SizeUnit::Em(Fixed::ONE) / SizeUnit::Pixel(Fixed::ZERO),
+        SizeUnit::Em(Fixed::ZERO)
+    );
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+use common::{nested, root};
+
+/// A root at 300 DPI holding a block in physical units.
+fn printed() -> Element {
+    let mut root = root((1000.0, 1000.0), LayoutType::Block);
+    root.sublayout.dpi = 300.0;
+    root.sublayout.add(Element::new(
+        (SizeUnit::Inch(1.0), SizeUnit::Point(36.0)),
+        (SizeUnit::Millimetre(12.7), SizeUnit::Pixel(10.0)),
+        LayoutType::Block,
+    ));
+
+    root
+}
+
+#[test]
+fn physical_units_use_the_dpi() {
+    let root = printed();
+    let element = nested(&root, &[0]);
+
+    assert_eq!(element.size, (300.0.into(), 150.0.into()));
+    assert_eq!(element.position, (150.0.into(), 10.0.into()));
+}
+
+#[test]
+fn physical_units_are_reported_as_authored() {
+    let root = printed();
+    let element = nested(&root, &[0]);
+
+    assert_eq!(
+        element.physical_size(),
+        (SizeUnit::Inch(1.0), SizeUnit::Point(36.0))
+    );
+    assert_eq!(
+        element.physical_position(),
+        (SizeUnit::Millimetre(12.7), SizeUnit::Pixel(10.0))
+    );
+    assert!(
+        element
+            .html()
+            .contains("left: 12.7mm; top: 10px; width: 1in; height: 36pt;")
+    );
+}
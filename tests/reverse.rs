@@ -0,0 +1,54 @@
+mod common;
+
+use clemen::layouts::{Direction, LayoutType, element::Element};
+use common::{element, positions, root};
+
+/// A reversed layout, which doesn't grow its elements.
+fn reversed(size: (f64, f64), variant: LayoutType) -> Element {
+    let mut root = root(size, variant);
+    root.sublayout.properties.flex_grow = false;
+    root.sublayout.reverse = true;
+
+    root
+}
+
+#[test]
+fn the_first_element_sits_at_the_far_edge() {
+    let mut root = reversed((300.0, 100.0), LayoutType::Block);
+    root.sublayout.add(element(50.0, 20.0));
+    root.sublayout.add(element(100.0, 20.0));
+
+    assert_eq!(positions(&root), [(250.0, 0.0), (150.0, 0.0)]);
+}
+
+#[test]
+fn wrapped_rows_are_reversed_too() {
+    let mut root = reversed((200.0, 100.0), LayoutType::Block);
+    for _ in 0..3 {
+        root.sublayout.add(element(80.0, 20.0));
+    }
+
+    assert_eq!(positions(&root), [(120.0, 0.0), (40.0, 0.0), (120.0, 20.0)]);
+}
+
+#[test]
+fn vertical_flexible_layouts_start_at_the_bottom() {
+    let mut root = reversed((200.0, 300.0), LayoutType::Flexible);
+    root.sublayout.properties.offset = 4.0.into();
+    root.sublayout.direction = Direction::Y;
+    root.sublayout.add(element(200.0, 30.0));
+    root.sublayout.add(element(200.0, 60.0));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(positions(&root), [(0.0, 270.0), (0.0, 206.0)]);
+}
+
+#[test]
+fn shrunk_elements_are_still_reversed() {
+    let mut root = reversed((100.0, 100.0), LayoutType::Flexible);
+    root.sublayout.add(element(100.0, 20.0));
+    root.sublayout.add(element(100.0, 20.0));
+    root.sublayout.resize_flexible();
+
+    assert_eq!(positions(&root), [(50.0, 0.0), (0.0, 0.0)]);
+}
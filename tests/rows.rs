@@ -0,0 +1,66 @@
+mod common;
+
+use clemen::layouts::{
+    LayoutType,
+    element::{Element, PositionStyle},
+};
+use common::{element, rects, root};
+
+/// A 100x200 block layout where the first element is the tallest in its row.
+fn tall_first(wrap_reverse: bool) -> Element {
+    let mut root = root((100.0, 200.0), LayoutType::Block);
+    root.sublayout.wrap_reverse = wrap_reverse;
+    root.sublayout.add(element(40.0, 50.0));
+    root.sublayout.add(element(40.0, 20.0));
+    root.sublayout.add(element(40.0, 20.0));
+
+    root
+}
+
+#[test]
+fn rows_start_below_the_first_element() {
+    assert_eq!(
+        rects(&tall_first(false)),
+        [
+            (0.0, 0.0, 40.0, 50.0),
+            (40.0, 0.0, 40.0, 20.0),
+            (0.0, 50.0, 40.0, 20.0)
+        ]
+    );
+}
+
+#[test]
+fn reversed_rows_start_above_the_first_element() {
+    assert_eq!(
+        rects(&tall_first(true)),
+        [
+            (0.0, 150.0, 40.0, 50.0),
+            (40.0, 180.0, 40.0, 20.0),
+            (0.0, 130.0, 40.0, 20.0)
+        ]
+    );
+}
+
+#[test]
+fn absolute_elements_dont_start_rows() {
+    let mut root = root((100.0, 200.0), LayoutType::Block);
+
+    let mut absolute = Element::new(
+        (10.0.into(), 10.0.into()),
+        (0.0.into(), 5.0.into()),
+        LayoutType::Block,
+    );
+    absolute.attrs.style = PositionStyle::Absolute;
+    root.sublayout.add(absolute);
+    root.sublayout.add(element(60.0, 20.0));
+    root.sublayout.add(element(60.0, 20.0));
+
+    assert_eq!(
+        rects(&root),
+        [
+            (0.0, 5.0, 10.0, 10.0),
+            (0.0, 0.0, 60.0, 20.0),
+            (0.0, 20.0, 60.0, 20.0)
+        ]
+    );
+}
@@ -0,0 +1,66 @@
+use clemen::layouts::{LayoutType, element::Element, fixed::Fixed, scalar::Scalar, unit::SizeUnit};
+
+fn root<S: Scalar>(scale_factor: f64, pixel_snap: bool) -> Element<S> {
+    let mut root = Element::new(
+        (S::from_f64(100.0).into(), S::from_f64(50.0).into()),
+        (S::ZERO.into(), S::ZERO.into()),
+        LayoutType::Flexible,
+    );
+    root.sublayout.scale_factor = scale_factor;
+    root.sublayout.pixel_snap = pixel_snap;
+
+    for _ in 0..3 {
+        root.sublayout.add(Element::new(
+            (SizeUnit::Fraction(S::ONE), S::from_f64(50.0).into()),
+            (S::ZERO.into(), S::ZERO.into()),
+            LayoutType::Block,
+        ));
+    }
+
+    root.sublayout.resize_flexible();
+    root
+}
+
+/// The (start, end) of the element at the given index, along the x axis.
+fn edges<S: Scalar>(root: &Element<S>, idx: usize) -> (S, S) {
+    let element = root.sublayout.get(idx).unwrap();
+    let start = element.position.0.clone().pixels();
+    (start, start + element.size.0.clone().pixels())
+}
+
+#[test]
+fn fixed_edges_are_shared() {
+    let root = root::<Fixed>(1.25, true);
+    let scale = Fixed::from_f64(1.25);
+
+    assert_eq!(edges(&root, 0).0, Fixed::ZERO);
+    assert_eq!(edges(&root, 0).1, edges(&root, 1).0);
+    assert_eq!(edges(&root, 1).1, edges(&root, 2).0);
+    assert_eq!(edges(&root, 2).1, Fixed::from_f64(100.0));
+
+    // 33.33 logical pixels is 41.67 device pixels, which rounds to 42
+    assert_eq!(edges(&root, 0).1, Fixed::from_f64(42.0) / scale);
+}
+
+#[test]
+fn float_edges_land_on_device_pixels() {
+    let root = root::<f64>(1.25, true);
+
+    let device_pixel = |edge: f64| ((edge * 1.25).round() - edge * 1.25).abs() < 1e-9;
+
+    for i in 0..3 {
+        let (start, end) = edges(&root, i);
+        assert!(device_pixel(start), "{start}");
+        assert!(device_pixel(end), "{end}");
+    }
+}
+
+#[test]
+fn integer_pixels_are_already_snapped() {
+    for scale_factor in [1.5, 2.0, 0.25] {
+        assert_eq!(
+            root::<i32>(scale_factor, true).html(),
+            root::<i32>(scale_factor, false).html()
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use clemen::layouts::unit::{Expression, SizeUnit};
+
+#[test]
+fn numbers_combine_with_any_unit() {
+    assert_eq!(SizeUnit::Pixel(10.0) + 5.0, SizeUnit::Pixel(15.0));
+    assert_eq!(SizeUnit::Em(2.0) * 2.0, SizeUnit::Em(4.0));
+    assert_eq!(SizeUnit::Vw(50.0) / 2.0, SizeUnit::Vw(25.0));
+    assert_eq!(
+        (SizeUnit::Percent(50.0) + 10.0).to_string(),
+        "calc(50% + 10px)"
+    );
+    assert_eq!((SizeUnit::Rem(1.0) - 2.0).to_string(), "calc(1rem - 2px)");
+
+    let mut unit = SizeUnit::Percent(100.0);
+    unit -= 20.0;
+    assert_eq!(unit.to_string(), "calc(100% - 20px)");
+}
+
+#[test]
+fn only_pixels_convert_to_numbers() {
+    assert_eq!(f64::try_from(SizeUnit::Pixel(3.0)), Ok(3.0));
+    assert_eq!(f64::try_from(SizeUnit::Em(1.0)), Err(SizeUnit::Em(1.0)));
+}
+
+#[test]
+fn checked_expressions() {
+    assert_eq!(
+        SizeUnit::checked_calc(Expression::Add(SizeUnit::Em(1.0), SizeUnit::Pixel(2.0))),
+        Some(SizeUnit::Calc(Box::new(Expression::Add(
+            SizeUnit::Em(1.0),
+            SizeUnit::Pixel(2.0)
+        ))))
+    );
+    assert_eq!(
+        SizeUnit::checked_calc(Expression::Add(
+            SizeUnit::Fraction(1.0),
+            SizeUnit::Pixel(2.0)
+        )),
+        None
+    );
+    assert_eq!(
+        SizeUnit::checked_calc(Expression::Min(SizeUnit::Auto, SizeUnit::Pixel(2.0))),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "auto cannot be used in an expression")]
+fn numbers_cant_combine_with_auto() {
+    let _ = SizeUnit::Auto + 10.0;
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use clemen::layouts::{LayoutType, element::Element, unit::SizeUnit};
+use common::{element, nested, root};
+
+/// The size of every element inside of the root's only child.
+fn grandchildren(root: &Element) -> Vec<(f64, f64)> {
+    (0..4)
+        .map(|i| {
+            let element = nested(root, &[0, i]);
+            (
+                element.size.0.clone().pixels(),
+                element.size.1.clone().pixels(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn resizing_the_root_resolves_grandchildren_again() {
+    let mut root = root((400.0, 200.0), LayoutType::Block);
+
+    let mut child = element(100.0, 100.0);
+    for (width, height) in [
+        (SizeUnit::Vw(10.0), SizeUnit::Vh(10.0)),
+        (SizeUnit::Vmin(10.0), SizeUnit::Vmax(10.0)),
+        (SizeUnit::Vh(10.0), SizeUnit::Vw(10.0)),
+        (SizeUnit::Vmax(10.0), SizeUnit::Vmin(10.0)),
+    ] {
+        child.sublayout.add(element(width, height));
+    }
+    root.sublayout.add(child);
+
+    assert_eq!(
+        grandchildren(&root),
+        [(40.0, 20.0), (20.0, 40.0), (20.0, 40.0), (40.0, 20.0)]
+    );
+
+    root.resize((100.0.into(), 300.0.into()));
+
+    assert_eq!(
+        grandchildren(&root),
+        [(10.0, 30.0), (10.0, 30.0), (30.0, 10.0), (30.0, 10.0)]
+    );
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use clemen::layouts::{LayoutType, Overflow, Padding};
+use common::{element, positions, root};
+
+#[test]
+fn rows_stack_upward_inside_of_the_padding() {
+    let mut root = root((300.0, 200.0), LayoutType::Block);
+    root.sublayout.properties.offset = 10.0.into();
+    root.sublayout.properties.padding = Padding::all(10.0.into());
+    root.sublayout.wrap_reverse = true;
+    for _ in 0..5 {
+        root.sublayout.add(element(120.0, 40.0));
+    }
+
+    assert_eq!(
+        positions(&root),
+        [
+            (10.0, 150.0),
+            (140.0, 150.0),
+            (10.0, 100.0),
+            (140.0, 100.0),
+            (10.0, 50.0)
+        ]
+    );
+}
+
+#[test]
+fn alignment_within_rows_is_flipped() {
+    let mut root = root((200.0, 100.0), LayoutType::Block);
+    root.sublayout.wrap_reverse = true;
+    root.sublayout.add(element(50.0, 20.0));
+    root.sublayout.add(element(50.0, 40.0));
+
+    // both elements sit at the bottom of the row, which is at the bottom edge
+    assert_eq!(positions(&root), [(0.0, 80.0), (50.0, 60.0)]);
+}
+
+#[test]
+fn wrapping_flexible_layouts_stack_upward() {
+    let mut root = root((100.0, 100.0), LayoutType::Flexible);
+    root.sublayout.properties.flex_grow = false;
+    root.sublayout.overflow = Overflow::Wrap;
+    root.sublayout.wrap_reverse = true;
+    for _ in 0..3 {
+        root.sublayout.add(element(80.0, 20.0));
+    }
+    root.sublayout.resize_flexible();
+
+    assert_eq!(positions(&root), [(0.0, 80.0), (0.0, 60.0), (0.0, 40.0)]);
+}